@@ -33,6 +33,42 @@ pub fn authority_keys_from_seed(s: &str) -> (AuraId, GrandpaId) {
     (get_from_seed::<AuraId>(s), get_from_seed::<GrandpaId>(s))
 }
 
+/// The environment variable naming a JSON file of PoE claims to include in the genesis.
+const GENESIS_CLAIMS_ENV: &str = "POE_GENESIS_CLAIMS";
+
+/// An entry of the genesis claims file, e.g.
+/// `{ "claim": "0x1234", "owner": "5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY" }`.
+#[derive(serde::Deserialize)]
+struct GenesisClaim {
+    /// The claim as `0x`-prefixed hex, or as plain text.
+    claim: String,
+    /// The SS58 address of the owner, which must be one of the endowed accounts.
+    owner: AccountId,
+}
+
+/// Load the PoE claims from the file named by `POE_GENESIS_CLAIMS`, if it is set.
+fn genesis_claims() -> Result<Vec<(Vec<u8>, AccountId)>, String> {
+    let Ok(path) = std::env::var(GENESIS_CLAIMS_ENV) else {
+        return Ok(Vec::new());
+    };
+    let file = std::fs::File::open(&path).map_err(|e| format!("Cannot open {}: {}", path, e))?;
+    let claims: Vec<GenesisClaim> = serde_json::from_reader(std::io::BufReader::new(file))
+        .map_err(|e| format!("Invalid genesis claims in {}: {}", path, e))?;
+
+    claims
+        .into_iter()
+        .map(|GenesisClaim { claim, owner }| {
+            let bytes = if claim.starts_with("0x") {
+                sp_core::bytes::from_hex(&claim)
+                    .map_err(|e| format!("Invalid hex claim {}: {}", claim, e))?
+            } else {
+                claim.into_bytes()
+            };
+            Ok((bytes, owner))
+        })
+        .collect()
+}
+
 pub fn development_config() -> Result<ChainSpec, String> {
     Ok(ChainSpec::builder(
         WASM_BINARY.ok_or_else(|| "Development wasm not available".to_string())?,
@@ -53,6 +89,7 @@ pub fn development_config() -> Result<ChainSpec, String> {
             get_account_id_from_seed::<sr25519::Public>("Alice//stash"),
             get_account_id_from_seed::<sr25519::Public>("Bob//stash"),
         ],
+        genesis_claims()?,
         true,
     ))
     .build())
@@ -89,6 +126,7 @@ pub fn local_testnet_config() -> Result<ChainSpec, String> {
             get_account_id_from_seed::<sr25519::Public>("Eve//stash"),
             get_account_id_from_seed::<sr25519::Public>("Ferdie//stash"),
         ],
+        genesis_claims()?,
         true,
     ))
     .build())
@@ -99,6 +137,7 @@ fn testnet_genesis(
     initial_authorities: Vec<(AuraId, GrandpaId)>,
     root_key: AccountId,
     endowed_accounts: Vec<AccountId>,
+    poe_claims: Vec<(Vec<u8>, AccountId)>,
     _enable_println: bool,
 ) -> serde_json::Value {
     serde_json::json!({
@@ -116,5 +155,9 @@ fn testnet_genesis(
             // Assign network admin rights.
             "key": Some(root_key),
         },
+        "poeModule": {
+            // Claims notarised from block 0.
+            "claims": poe_claims,
+        },
     })
 }
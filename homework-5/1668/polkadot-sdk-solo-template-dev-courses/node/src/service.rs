@@ -202,11 +202,13 @@ pub fn new_full(config: Configuration) -> Result<TaskManager, ServiceError> {
     let rpc_extensions_builder = {
         let client = client.clone();
         let pool = transaction_pool.clone();
+        let offchain_storage = backend.offchain_storage();
 
         Box::new(move |deny_unsafe, _| {
             let deps = crate::rpc::FullDeps {
                 client: client.clone(),
                 pool: pool.clone(),
+                offchain_storage: offchain_storage.clone(),
                 deny_unsafe,
             };
             crate::rpc::create_full(deps).map_err(Into::into)
@@ -0,0 +1,194 @@
+//! RPC interface for the PoE pallet.
+
+#![warn(missing_docs)]
+
+use std::{marker::PhantomData, sync::Arc};
+
+use codec::Codec;
+use jsonrpsee::{
+    core::RpcResult,
+    proc_macros::rpc,
+    types::{error::ErrorObject, ErrorObjectOwned},
+};
+use sc_rpc_api::DenyUnsafe;
+use serde::{de::DeserializeOwned, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::{offchain::OffchainStorage, Bytes};
+use sp_runtime::traits::Block as BlockT;
+
+pub use pallet_poe_runtime_api::{
+    offchain_content_key, ClaimDetails, NamespaceId, PoeApi as PoeRuntimeApi, ProvenanceRecord,
+    DEFAULT_NAMESPACE,
+};
+
+/// PoE RPC methods.
+#[rpc(client, server)]
+pub trait PoeApi<BlockHash, AccountId, BlockNumber> {
+    /// The details of `claim` in `namespace`, [`DEFAULT_NAMESPACE`] if omitted, or `None` if it
+    /// has never been created.
+    #[method(name = "poe_getClaim")]
+    fn claim(
+        &self,
+        claim: Bytes,
+        namespace: Option<NamespaceId>,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<ClaimDetails<AccountId, BlockNumber>>>;
+
+    /// Up to `limit` active claims owned by `account` in any namespace, as
+    /// `(namespace, claim)`, starting after `cursor`.
+    #[method(name = "poe_claimsOf")]
+    fn claims_of(
+        &self,
+        account: AccountId,
+        cursor: Option<(NamespaceId, Bytes)>,
+        limit: u32,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<(NamespaceId, Bytes)>>;
+
+    /// The ownership history of `claim` in `namespace`, [`DEFAULT_NAMESPACE`] if omitted,
+    /// oldest first.
+    #[method(name = "poe_claimHistory")]
+    fn claim_history(
+        &self,
+        claim: Bytes,
+        namespace: Option<NamespaceId>,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<ProvenanceRecord<AccountId, BlockNumber>>>;
+
+    /// The total number of claims stored on chain.
+    #[method(name = "poe_claimCount")]
+    fn claim_count(&self, at: Option<BlockHash>) -> RpcResult<u64>;
+}
+
+/// RPC methods to read the content of claims from the offchain database.
+#[rpc(client, server)]
+pub trait PoeContentApi<Hash> {
+    /// The content hashed to `hash` by `create_claim_from_content`, if this node indexed it.
+    /// The node must run with `--enable-offchain-indexing` when the claim is imported. This
+    /// reads the node's offchain database, so it is an unsafe RPC method.
+    #[method(name = "poe_getContent")]
+    fn content(&self, hash: Hash) -> RpcResult<Option<Bytes>>;
+}
+
+/// Provides RPC methods to query PoE claims.
+pub struct Poe<C, Block> {
+    client: Arc<C>,
+    _marker: PhantomData<Block>,
+}
+
+impl<C, Block> Poe<C, Block> {
+    /// Creates a new instance of the PoE RPC helper.
+    pub fn new(client: Arc<C>) -> Self {
+        Self { client, _marker: Default::default() }
+    }
+}
+
+/// Error type of this RPC api.
+pub enum Error {
+    /// The call to runtime failed.
+    RuntimeError,
+}
+
+impl From<Error> for i32 {
+    fn from(e: Error) -> i32 {
+        match e {
+            Error::RuntimeError => 1,
+        }
+    }
+}
+
+fn runtime_error(message: &'static str, e: impl ToString) -> ErrorObjectOwned {
+    ErrorObject::owned(Error::RuntimeError.into(), message, Some(e.to_string()))
+}
+
+impl<C, Block, AccountId, BlockNumber>
+    PoeApiServer<<Block as BlockT>::Hash, AccountId, BlockNumber> for Poe<C, Block>
+where
+    Block: BlockT,
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: PoeRuntimeApi<Block, AccountId, BlockNumber, Block::Hash>,
+    AccountId: Codec + Serialize + DeserializeOwned + Send + Sync + 'static,
+    BlockNumber: Codec + Serialize + Send + Sync + 'static,
+{
+    fn claim(
+        &self,
+        claim: Bytes,
+        namespace: Option<NamespaceId>,
+        at: Option<Block::Hash>,
+    ) -> RpcResult<Option<ClaimDetails<AccountId, BlockNumber>>> {
+        let api = self.client.runtime_api();
+        let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.claim(at_hash, namespace.unwrap_or(DEFAULT_NAMESPACE), claim.to_vec())
+            .map_err(|e| runtime_error("Unable to query claim.", e))
+    }
+
+    fn claims_of(
+        &self,
+        account: AccountId,
+        cursor: Option<(NamespaceId, Bytes)>,
+        limit: u32,
+        at: Option<Block::Hash>,
+    ) -> RpcResult<Vec<(NamespaceId, Bytes)>> {
+        let api = self.client.runtime_api();
+        let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        let cursor = cursor.map(|(namespace, claim)| (namespace, claim.to_vec()));
+        let claims = api
+            .claims_of(at_hash, account, cursor, limit)
+            .map_err(|e| runtime_error("Unable to query claims of account.", e))?;
+        Ok(claims.into_iter().map(|(namespace, claim)| (namespace, Bytes::from(claim))).collect())
+    }
+
+    fn claim_history(
+        &self,
+        claim: Bytes,
+        namespace: Option<NamespaceId>,
+        at: Option<Block::Hash>,
+    ) -> RpcResult<Vec<ProvenanceRecord<AccountId, BlockNumber>>> {
+        let api = self.client.runtime_api();
+        let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.claim_history(at_hash, namespace.unwrap_or(DEFAULT_NAMESPACE), claim.to_vec())
+            .map_err(|e| runtime_error("Unable to query claim history.", e))
+    }
+
+    fn claim_count(&self, at: Option<Block::Hash>) -> RpcResult<u64> {
+        let api = self.client.runtime_api();
+        let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.claim_count(at_hash)
+            .map_err(|e| runtime_error("Unable to query claim count.", e))
+    }
+}
+
+/// Provides RPC methods to read claim content from the offchain database.
+pub struct PoeContent<S, Hash> {
+    storage: S,
+    deny_unsafe: DenyUnsafe,
+    _marker: PhantomData<Hash>,
+}
+
+impl<S, Hash> PoeContent<S, Hash> {
+    /// Creates a new instance reading from the offchain `storage` of the node.
+    pub fn new(storage: S, deny_unsafe: DenyUnsafe) -> Self {
+        Self { storage, deny_unsafe, _marker: Default::default() }
+    }
+}
+
+impl<S, Hash> PoeContentApiServer<Hash> for PoeContent<S, Hash>
+where
+    S: OffchainStorage + 'static,
+    Hash: Codec + DeserializeOwned + Send + Sync + 'static,
+{
+    fn content(&self, hash: Hash) -> RpcResult<Option<Bytes>> {
+        self.deny_unsafe.check_if_safe()?;
+
+        // 链下索引写入的数据位于持久化存储的前缀下
+        Ok(self
+            .storage
+            .get(sp_offchain::STORAGE_PREFIX, &offchain_content_key(&hash))
+            .map(Bytes::from))
+    }
+}
@@ -0,0 +1,44 @@
+//! Runtime API definition for the PoE pallet.
+//!
+//! Lets clients look up claims by their raw bytes instead of rebuilding the storage keys of
+//! `Proofs` and `ClaimsByOwner` by hand.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use sp_std::vec::Vec;
+
+pub use pallet_poe::{
+    offchain_content_key, ClaimDetails, NamespaceId, ProvenanceAction, ProvenanceRecord,
+    DEFAULT_NAMESPACE,
+};
+
+sp_api::decl_runtime_apis! {
+    /// The API to query proof-of-existence claims.
+    pub trait PoeApi<AccountId, BlockNumber, Hash> where
+        AccountId: Codec,
+        BlockNumber: Codec,
+        Hash: Codec,
+    {
+        /// The details of `claim` in `namespace`, or `None` if it has never been created.
+        fn claim(namespace: NamespaceId, claim: Vec<u8>) -> Option<ClaimDetails<AccountId, BlockNumber>>;
+        /// Up to `limit` active claims owned by `account` in any namespace, as
+        /// `(namespace, claim)`, starting after `cursor`.
+        fn claims_of(
+            account: AccountId,
+            cursor: Option<(NamespaceId, Vec<u8>)>,
+            limit: u32,
+        ) -> Vec<(NamespaceId, Vec<u8>)>;
+        /// The ownership history of `claim` in `namespace`, oldest first.
+        fn claim_history(
+            namespace: NamespaceId,
+            claim: Vec<u8>,
+        ) -> Vec<ProvenanceRecord<AccountId, BlockNumber>>;
+        /// The total number of claims stored on chain.
+        fn claim_count() -> u64;
+        /// Whether the document hashed to `leaf` is included in the anchored Merkle `root`,
+        /// given the sibling hashes in `proof`. Nodes are hashed with the domain prefixes
+        /// `MERKLE_LEAF_PREFIX` and `MERKLE_NODE_PREFIX`.
+        fn verify_inclusion(leaf: Hash, proof: Vec<Hash>, root: Hash) -> bool;
+    }
+}
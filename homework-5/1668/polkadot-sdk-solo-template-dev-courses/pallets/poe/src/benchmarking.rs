@@ -1,35 +1,873 @@
-//! Benchmarking setup for pallet-template
+//! Benchmarking setup for pallet-poe
 #![cfg(feature = "runtime-benchmarks")]
 use super::*;
 
 #[allow(unused)]
-use crate::Pallet as Template;
+use crate::Pallet as PoeModule;
+use alloc::vec::Vec;
 use frame_benchmarking::v2::*;
-use frame_system::RawOrigin;
+use frame_support::{
+    sp_runtime::traits::{Hash, IdentifyAccount, Saturating},
+    traits::{
+        fungible::{Inspect, Mutate},
+        EnsureOrigin, Get,
+    },
+    BoundedVec,
+};
+use frame_system::{pallet_prelude::BlockNumberFor, RawOrigin};
+
+const SEED: u32 = 0;
+
+/// A claim of `len` bytes.
+fn claim_of_len<T: Config>(len: u32) -> BoundedVec<u8, T::MaxClaimLength> {
+    BoundedVec::try_from(alloc::vec![0xcd; len as usize]).expect("len is at most MaxClaimLength")
+}
+
+/// The number of items that may succeed, and separately fail, in a benchmarked batch.
+fn max_batch<T: Config>() -> u32 {
+    T::MaxBatchSize::get().min(T::MaxClaimsPerOwner::get())
+}
+
+/// The bytes a full batch holds beyond the index that keeps its claims distinct.
+fn max_padding<T: Config>() -> u32 {
+    max_batch::<T>().saturating_mul(T::MaxClaimLength::get().saturating_sub(4))
+}
+
+/// Distinct claims with the indices in `indices`, padded with as many of the `padding` bytes
+/// left as fit.
+fn padded_claims<T: Config>(
+    indices: core::ops::Range<u32>,
+    padding: &mut u32,
+) -> Vec<BoundedVec<u8, T::MaxClaimLength>> {
+    let max_len = T::MaxClaimLength::get() as usize;
+    indices
+        .map(|index| {
+            let mut claim = index.to_le_bytes().to_vec();
+            claim.truncate(max_len);
+            let extra = (*padding as usize).min(max_len - claim.len());
+            claim.resize(claim.len() + extra, 0xcd);
+            *padding -= extra as u32;
+            BoundedVec::try_from(claim).expect("the claim has at most MaxClaimLength bytes")
+        })
+        .collect()
+}
+
+/// Create each of `claims` for `owner`.
+fn create_each<T: Config>(
+    owner: &T::AccountId,
+    claims: &[BoundedVec<u8, T::MaxClaimLength>],
+) -> Result<(), BenchmarkError> {
+    for claim in claims {
+        PoeModule::<T>::create_claim(RawOrigin::Signed(owner.clone()).into(), claim.clone(), None, None)
+            .map_err(|_| BenchmarkError::Stop("failed to create the claims"))?;
+    }
+    Ok(())
+}
+
+/// Metadata of the maximum size, which makes the deposit and the storage writes largest.
+fn max_metadata<T: Config>() -> ClaimMetadata<T> {
+    ClaimMetadata {
+        content_type: BoundedVec::try_from(alloc::vec![b'a'; T::MaxContentTypeLength::get() as usize])
+            .unwrap(),
+        uri: BoundedVec::try_from(alloc::vec![b'u'; T::MaxUriLength::get() as usize]).unwrap(),
+        description: BoundedVec::try_from(alloc::vec![b'd'; T::MaxDescriptionLength::get() as usize])
+            .unwrap(),
+    }
+}
+
+/// Give `who` enough funds to hold the deposits of as many claims as it may own and a
+/// challenge bond.
+fn fund<T: Config>(who: &T::AccountId) {
+    let bytes = T::MaxClaimLength::get()
+        .saturating_add(T::MaxContentTypeLength::get())
+        .saturating_add(T::MaxUriLength::get())
+        .saturating_add(T::MaxDescriptionLength::get());
+    let deposit = T::ClaimDeposit::get()
+        .saturating_add(T::DepositPerByte::get().saturating_mul(bytes.into()));
+    let claims = T::MaxClaimsPerOwner::get().saturating_add(1);
+    T::Currency::set_balance(
+        who,
+        T::Currency::minimum_balance()
+            .saturating_add(deposit.saturating_mul(claims.into()))
+            .saturating_add(T::ChallengeBond::get()),
+    );
+}
+
+/// A funded account other than the caller.
+fn funded_account<T: Config>(name: &'static str, index: u32) -> T::AccountId {
+    let who = account(name, index, SEED);
+    fund::<T>(&who);
+    who
+}
+
+/// Create a claim of `len` bytes with maximal metadata and a lifetime, owned by `owner`.
+fn create_max_claim<T: Config>(
+    owner: &T::AccountId,
+    len: u32,
+) -> Result<BoundedVec<u8, T::MaxClaimLength>, BenchmarkError> {
+    let claim = claim_of_len::<T>(len);
+    PoeModule::<T>::create_claim(
+        RawOrigin::Signed(owner.clone()).into(),
+        claim.clone(),
+        Some(1000u32.into()),
+        Some(max_metadata::<T>()),
+    )
+    .map_err(|_| BenchmarkError::Stop("failed to create the claim"))?;
+    Ok(claim)
+}
+
+/// Offer `claim` of `owner` to `to`.
+fn offer<T: Config>(
+    owner: &T::AccountId,
+    claim: &BoundedVec<u8, T::MaxClaimLength>,
+    to: &T::AccountId,
+) -> Result<(), BenchmarkError> {
+    PoeModule::<T>::offer_claim(RawOrigin::Signed(owner.clone()).into(), claim.clone(), to.clone())
+        .map_err(|_| BenchmarkError::Stop("failed to offer the claim"))?;
+    Ok(())
+}
+
+/// Make `claim` jointly owned by `owner` and `MaxCoOwners - 1` other accounts, and let each of
+/// the others approve a different lock, so that the claim has as many pending approvals as it
+/// can. Returns the other co-owners.
+fn make_joint<T: Config>(
+    owner: &T::AccountId,
+    claim: &BoundedVec<u8, T::MaxClaimLength>,
+    threshold: u32,
+) -> Result<Vec<T::AccountId>, BenchmarkError> {
+    let others: Vec<T::AccountId> = (1..T::MaxCoOwners::get())
+        .map(|index| account("co_owner", index, SEED))
+        .collect();
+    let co_owners = core::iter::once(owner.clone()).chain(others.iter().cloned()).collect::<Vec<_>>();
+    PoeModule::<T>::set_co_owners(
+        RawOrigin::Signed(owner.clone()).into(),
+        claim.clone(),
+        BoundedVec::try_from(co_owners).expect("there are MaxCoOwners co-owners"),
+        threshold,
+    )
+    .map_err(|_| BenchmarkError::Stop("failed to set the co-owners"))?;
+    for (index, co_owner) in others.iter().enumerate() {
+        approve_lock::<T>(co_owner, claim, index as u32)?;
+    }
+    Ok(others)
+}
+
+/// Let `who` approve locking `claim` until a block that depends on `index`.
+fn approve_lock<T: Config>(
+    who: &T::AccountId,
+    claim: &BoundedVec<u8, T::MaxClaimLength>,
+    index: u32,
+) -> Result<(), BenchmarkError> {
+    let until: BlockNumberFor<T> = 1000u32.saturating_add(index).into();
+    PoeModule::<T>::approve_action(
+        RawOrigin::Signed(who.clone()).into(),
+        claim.clone(),
+        JointAction::Lock { until, beneficiary: None },
+    )
+    .map_err(|_| BenchmarkError::Stop("failed to approve the lock"))?;
+    Ok(())
+}
+
+/// Let `challenger` challenge `claim`.
+fn challenge<T: Config>(
+    challenger: &T::AccountId,
+    claim: &BoundedVec<u8, T::MaxClaimLength>,
+) -> Result<(), BenchmarkError> {
+    PoeModule::<T>::challenge_claim(RawOrigin::Signed(challenger.clone()).into(), claim.clone())
+        .map_err(|_| BenchmarkError::Stop("failed to challenge the claim"))?;
+    Ok(())
+}
+
+/// Create a claim from content owned by `owner` and return the content hash.
+fn create_hashed_claim<T: Config>(owner: &T::AccountId) -> Result<T::Hash, BenchmarkError> {
+    let content: BoundedVec<u8, T::MaxContentLength> =
+        BoundedVec::try_from(alloc::vec![0xcd; T::MaxContentLength::get() as usize])
+            .expect("the content has MaxContentLength bytes");
+    let hash = T::Hashing::hash(&content);
+    PoeModule::<T>::create_claim_from_content(RawOrigin::Signed(owner.clone()).into(), content)
+        .map_err(|_| BenchmarkError::Stop("failed to create the claim"))?;
+    Ok(hash)
+}
+
+/// A namespace managed by `admin`, created without going through `ForceOrigin`.
+fn add_namespace<T: Config>(admin: &T::AccountId, policy: NamespacePolicy) -> NamespaceId {
+    let namespace = NextNamespaceId::<T>::get();
+    NextNamespaceId::<T>::put(namespace.saturating_add(1));
+    Namespaces::<T>::insert(namespace, Namespace { admin: admin.clone(), policy });
+    namespace
+}
+
+/// The owner of `claim` in `namespace`, if it exists.
+fn owner_of<T: Config>(
+    namespace: NamespaceId,
+    claim: &BoundedVec<u8, T::MaxClaimLength>,
+) -> Option<T::AccountId> {
+    Proofs::<T>::get(namespace, claim).map(|info| info.owner)
+}
 
 #[benchmarks]
 mod benchmarks {
     use super::*;
 
     #[benchmark]
-    fn do_something() {
-        let value = 100u32.into();
+    fn create_claim(l: Linear<1, { T::MaxClaimLength::get() }>) {
+        let caller: T::AccountId = whitelisted_caller();
+        fund::<T>(&caller);
+        let claim = claim_of_len::<T>(l);
+
+        #[extrinsic_call]
+        create_claim(
+            RawOrigin::Signed(caller.clone()),
+            claim.clone(),
+            Some(1000u32.into()),
+            Some(max_metadata::<T>()),
+        );
+
+        assert_eq!(Proofs::<T>::get(DEFAULT_NAMESPACE, &claim).map(|info| info.owner), Some(caller));
+    }
+
+    #[benchmark]
+    fn revoke_claim(l: Linear<1, { T::MaxClaimLength::get() }>) -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+        fund::<T>(&caller);
+        let claim = create_max_claim::<T>(&caller, l)?;
+        offer::<T>(&caller, &claim, &account("recipient", 0, SEED))?;
+
+        #[extrinsic_call]
+        revoke_claim(RawOrigin::Signed(caller), claim.clone(), RevocationReason::Withdrawn);
+
+        assert!(Tombstones::<T>::contains_key(DEFAULT_NAMESPACE, &claim));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn transfer_claim(l: Linear<1, { T::MaxClaimLength::get() }>) -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+        let new_owner: T::AccountId = account("new_owner", 0, SEED);
+        fund::<T>(&caller);
+        fund::<T>(&new_owner);
+        let claim = create_max_claim::<T>(&caller, l)?;
+        offer::<T>(&caller, &claim, &account("recipient", 0, SEED))?;
+
+        #[extrinsic_call]
+        transfer_claim(RawOrigin::Signed(caller), claim.clone(), new_owner.clone());
+
+        assert_eq!(Proofs::<T>::get(DEFAULT_NAMESPACE, &claim).map(|info| info.owner), Some(new_owner));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn renew_claim(l: Linear<1, { T::MaxClaimLength::get() }>) -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+        fund::<T>(&caller);
+        let claim = create_max_claim::<T>(&caller, l)?;
+        let expires_at = Expiries::<T>::get(DEFAULT_NAMESPACE, &claim).ok_or(BenchmarkError::Stop("the claim does not expire"))?;
+
+        #[extrinsic_call]
+        renew_claim(RawOrigin::Signed(caller), claim.clone(), 1000u32.into());
+
+        assert_eq!(
+            Expiries::<T>::get(DEFAULT_NAMESPACE, &claim),
+            Some(expires_at.saturating_add(1000u32.into()))
+        );
+        Ok(())
+    }
+
+    #[benchmark]
+    fn offer_claim(l: Linear<1, { T::MaxClaimLength::get() }>) -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+        let to: T::AccountId = account("recipient", 1, SEED);
+        fund::<T>(&caller);
+        let claim = create_max_claim::<T>(&caller, l)?;
+        // 替换已有的要约
+        offer::<T>(&caller, &claim, &account("recipient", 0, SEED))?;
+
+        #[extrinsic_call]
+        offer_claim(RawOrigin::Signed(caller), claim.clone(), to.clone());
+
+        assert_eq!(PendingTransfers::<T>::get(DEFAULT_NAMESPACE, &claim).map(|offer| offer.to), Some(to));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn accept_claim(l: Linear<1, { T::MaxClaimLength::get() }>) -> Result<(), BenchmarkError> {
+        let owner = funded_account::<T>("owner", 0);
+        let caller: T::AccountId = whitelisted_caller();
+        fund::<T>(&caller);
+        let claim = create_max_claim::<T>(&owner, l)?;
+        offer::<T>(&owner, &claim, &caller)?;
+
+        #[extrinsic_call]
+        accept_claim(RawOrigin::Signed(caller.clone()), claim.clone());
+
+        assert_eq!(owner_of::<T>(DEFAULT_NAMESPACE, &claim), Some(caller));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn cancel_offer(l: Linear<1, { T::MaxClaimLength::get() }>) -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+        fund::<T>(&caller);
+        let claim = create_max_claim::<T>(&caller, l)?;
+        offer::<T>(&caller, &claim, &account("recipient", 0, SEED))?;
+
+        #[extrinsic_call]
+        cancel_offer(RawOrigin::Signed(caller), claim.clone());
+
+        assert!(!PendingTransfers::<T>::contains_key(DEFAULT_NAMESPACE, &claim));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn set_co_owners(
+        l: Linear<1, { T::MaxClaimLength::get() }>,
+        c: Linear<1, { T::MaxCoOwners::get() }>,
+    ) -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+        fund::<T>(&caller);
+        let claim = create_max_claim::<T>(&caller, l)?;
+        offer::<T>(&caller, &claim, &account("recipient", 0, SEED))?;
+        let co_owners = core::iter::once(caller.clone())
+            .chain((1..c).map(|index| account("co_owner", index, SEED)))
+            .collect::<Vec<_>>();
+        let co_owners: BoundedVec<_, T::MaxCoOwners> =
+            BoundedVec::try_from(co_owners).expect("c is at most MaxCoOwners");
+
+        #[extrinsic_call]
+        set_co_owners(RawOrigin::Signed(caller), claim.clone(), co_owners, c);
+
+        assert!(CoOwners::<T>::contains_key(DEFAULT_NAMESPACE, &claim));
+        Ok(())
+    }
+
+    // 最后一个批准执行转移：撤回调用者此前的批准，并清除所有其他操作的批准
+    #[benchmark]
+    fn approve_action(l: Linear<1, { T::MaxClaimLength::get() }>) -> Result<(), BenchmarkError> {
+        if T::MaxCoOwners::get() < 2 {
+            return Err(BenchmarkError::Stop("approvals need at least two co-owners"));
+        }
+        let caller: T::AccountId = whitelisted_caller();
+        let new_owner = funded_account::<T>("new_owner", 0);
+        fund::<T>(&caller);
+        let claim = create_max_claim::<T>(&caller, l)?;
+        let others = make_joint::<T>(&caller, &claim, 2)?;
+        let action = JointAction::Transfer(new_owner.clone());
+        PoeModule::<T>::approve_action(
+            RawOrigin::Signed(others[0].clone()).into(),
+            claim.clone(),
+            action.clone(),
+        )
+        .map_err(|_| BenchmarkError::Stop("failed to approve the transfer"))?;
+        approve_lock::<T>(&caller, &claim, T::MaxCoOwners::get())?;
+
+        #[extrinsic_call]
+        approve_action(RawOrigin::Signed(caller), claim.clone(), action);
+
+        assert_eq!(owner_of::<T>(DEFAULT_NAMESPACE, &claim), Some(new_owner));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn create_claim_from_content(c: Linear<1, { T::MaxContentLength::get() }>) {
+        let caller: T::AccountId = whitelisted_caller();
+        fund::<T>(&caller);
+        let content: BoundedVec<u8, T::MaxContentLength> =
+            BoundedVec::try_from(alloc::vec![0xcd; c as usize]).expect("c is at most MaxContentLength");
+        let hash = T::Hashing::hash(&content);
+
+        #[extrinsic_call]
+        create_claim_from_content(RawOrigin::Signed(caller.clone()), content);
+
+        assert_eq!(HashedClaims::<T>::get(hash).map(|claim| claim.owner), Some(caller));
+    }
+
+    // `n` 项成功、`f` 项因存证属于他人而失败，`b` 为填充的总字节数。
+    // 成功与失败的项目合计可能超过 MaxBatchSize，因此直接运行批处理。
+    #[benchmark]
+    fn create_claims(
+        n: Linear<0, { max_batch::<T>() }>,
+        f: Linear<0, { max_batch::<T>() }>,
+        b: Linear<0, { max_padding::<T>() }>,
+    ) -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+        fund::<T>(&caller);
+        let mut padding = b;
+        let claims = padded_claims::<T>(0..n, &mut padding);
+        let taken = padded_claims::<T>(n..n + f, &mut padding);
+        create_each::<T>(&funded_account::<T>("other", 0), &taken)?;
+
+        #[block]
+        {
+            PoeModule::<T>::run_batch(
+                claims.into_iter().chain(taken),
+                BatchMode::BestEffort,
+                T::WeightInfo::create_claims,
+                |claim| PoeModule::<T>::do_create(caller.clone(), DEFAULT_NAMESPACE, claim, None, None),
+            )?;
+        }
+
+        assert_eq!(OwnerClaimCount::<T>::get(&caller), n);
+        Ok(())
+    }
+
+    #[benchmark]
+    fn revoke_claims(
+        n: Linear<0, { max_batch::<T>() }>,
+        f: Linear<0, { max_batch::<T>() }>,
+        b: Linear<0, { max_padding::<T>() }>,
+    ) -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+        fund::<T>(&caller);
+        let mut padding = b;
+        let claims = padded_claims::<T>(0..n, &mut padding);
+        let taken = padded_claims::<T>(n..n + f, &mut padding);
+        create_each::<T>(&caller, &claims)?;
+        create_each::<T>(&funded_account::<T>("other", 0), &taken)?;
+
+        #[block]
+        {
+            PoeModule::<T>::run_batch(
+                claims.into_iter().chain(taken),
+                BatchMode::BestEffort,
+                T::WeightInfo::revoke_claims,
+                |claim| {
+                    PoeModule::<T>::try_revoke(
+                        caller.clone(),
+                        DEFAULT_NAMESPACE,
+                        claim,
+                        RevocationReason::Withdrawn,
+                    )
+                },
+            )?;
+        }
+
+        assert_eq!(OwnerClaimCount::<T>::get(&caller), 0);
+        Ok(())
+    }
+
+    #[benchmark]
+    fn transfer_claims(
+        n: Linear<0, { max_batch::<T>() }>,
+        f: Linear<0, { max_batch::<T>() }>,
+        b: Linear<0, { max_padding::<T>() }>,
+    ) -> Result<(), BenchmarkError> {
         let caller: T::AccountId = whitelisted_caller();
+        let new_owner = funded_account::<T>("new_owner", 0);
+        fund::<T>(&caller);
+        let mut padding = b;
+        let claims = padded_claims::<T>(0..n, &mut padding);
+        let taken = padded_claims::<T>(n..n + f, &mut padding);
+        create_each::<T>(&caller, &claims)?;
+        create_each::<T>(&funded_account::<T>("other", 0), &taken)?;
+
+        #[block]
+        {
+            PoeModule::<T>::run_batch(
+                claims.into_iter().chain(taken),
+                BatchMode::BestEffort,
+                T::WeightInfo::transfer_claims,
+                |claim| {
+                    PoeModule::<T>::try_transfer(caller.clone(), DEFAULT_NAMESPACE, claim, new_owner.clone())
+                },
+            )?;
+        }
+
+        assert_eq!(OwnerClaimCount::<T>::get(&new_owner), n);
+        Ok(())
+    }
+
+    #[benchmark]
+    fn anchor_root() {
+        let caller: T::AccountId = whitelisted_caller();
+        fund::<T>(&caller);
+        let root = T::Hashing::hash(b"root");
+
+        #[extrinsic_call]
+        anchor_root(RawOrigin::Signed(caller), root);
+
+        assert!(Roots::<T>::contains_key(root));
+    }
+
+    #[benchmark]
+    fn remove_root() -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+        fund::<T>(&caller);
+        let root = T::Hashing::hash(b"root");
+        PoeModule::<T>::anchor_root(RawOrigin::Signed(caller.clone()).into(), root)
+            .map_err(|_| BenchmarkError::Stop("failed to anchor the root"))?;
+
         #[extrinsic_call]
-        do_something(RawOrigin::Signed(caller), value);
+        remove_root(RawOrigin::Signed(caller), root);
 
-        assert_eq!(Something::<T>::get(), Some(value));
+        assert!(!Roots::<T>::contains_key(root));
+        Ok(())
     }
 
+    // 原存证没有元数据，设置后需要追加锁定押金
     #[benchmark]
-    fn cause_error() {
-        Something::<T>::put(100u32);
+    fn set_claim_metadata(l: Linear<1, { T::MaxClaimLength::get() }>) -> Result<(), BenchmarkError> {
         let caller: T::AccountId = whitelisted_caller();
+        fund::<T>(&caller);
+        let claim = claim_of_len::<T>(l);
+        PoeModule::<T>::create_claim(RawOrigin::Signed(caller.clone()).into(), claim.clone(), None, None)
+            .map_err(|_| BenchmarkError::Stop("failed to create the claim"))?;
+
+        #[extrinsic_call]
+        set_claim_metadata(RawOrigin::Signed(caller), claim.clone(), max_metadata::<T>());
+
+        assert!(Metadata::<T>::contains_key(DEFAULT_NAMESPACE, &claim));
+        Ok(())
+    }
+
+    // 由所有者的操作人而非所有者本人授权
+    #[benchmark]
+    fn approve(l: Linear<1, { T::MaxClaimLength::get() }>) -> Result<(), BenchmarkError> {
+        let owner = funded_account::<T>("owner", 0);
+        let caller: T::AccountId = whitelisted_caller();
+        let operator: T::AccountId = account("operator", 0, SEED);
+        let claim = create_max_claim::<T>(&owner, l)?;
+        PoeModule::<T>::set_operator(RawOrigin::Signed(owner).into(), caller.clone(), true)
+            .map_err(|_| BenchmarkError::Stop("failed to set the operator"))?;
+
         #[extrinsic_call]
-        cause_error(RawOrigin::Signed(caller));
+        approve(RawOrigin::Signed(caller), claim.clone(), Some(operator.clone()));
+
+        assert_eq!(ClaimOperators::<T>::get(DEFAULT_NAMESPACE, &claim), Some(operator));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn set_operator() {
+        let caller: T::AccountId = whitelisted_caller();
+        let operator: T::AccountId = account("operator", 0, SEED);
+
+        #[extrinsic_call]
+        set_operator(RawOrigin::Signed(caller.clone()), operator.clone(), true);
+
+        assert!(Operators::<T>::contains_key(&caller, &operator));
+    }
+
+    #[benchmark]
+    fn transfer_claim_from(l: Linear<1, { T::MaxClaimLength::get() }>) -> Result<(), BenchmarkError> {
+        let owner = funded_account::<T>("owner", 0);
+        let new_owner = funded_account::<T>("new_owner", 0);
+        let caller: T::AccountId = whitelisted_caller();
+        let claim = create_max_claim::<T>(&owner, l)?;
+        offer::<T>(&owner, &claim, &account("recipient", 0, SEED))?;
+        PoeModule::<T>::set_operator(RawOrigin::Signed(owner.clone()).into(), caller.clone(), true)
+            .map_err(|_| BenchmarkError::Stop("failed to set the operator"))?;
+
+        #[extrinsic_call]
+        transfer_claim_from(RawOrigin::Signed(caller), claim.clone(), owner, new_owner.clone());
+
+        assert_eq!(owner_of::<T>(DEFAULT_NAMESPACE, &claim), Some(new_owner));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn force_create_claim(l: Linear<1, { T::MaxClaimLength::get() }>) -> Result<(), BenchmarkError> {
+        let origin = T::ForceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let owner = funded_account::<T>("owner", 0);
+        let claim = claim_of_len::<T>(l);
+
+        #[extrinsic_call]
+        force_create_claim(origin as T::RuntimeOrigin, claim.clone(), owner.clone());
+
+        assert_eq!(owner_of::<T>(DEFAULT_NAMESPACE, &claim), Some(owner));
+        Ok(())
+    }
+
+    // 共有存证带有最多的待定批准和未决的质疑，转移时全部清除
+    #[benchmark]
+    fn force_transfer_claim(l: Linear<1, { T::MaxClaimLength::get() }>) -> Result<(), BenchmarkError> {
+        let origin = T::ForceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let owner = funded_account::<T>("owner", 0);
+        let new_owner = funded_account::<T>("new_owner", 0);
+        let claim = create_max_claim::<T>(&owner, l)?;
+        offer::<T>(&owner, &claim, &account("recipient", 0, SEED))?;
+        make_joint::<T>(&owner, &claim, T::MaxCoOwners::get())?;
+        challenge::<T>(&funded_account::<T>("challenger", 0), &claim)?;
+
+        #[extrinsic_call]
+        force_transfer_claim(origin as T::RuntimeOrigin, claim.clone(), new_owner.clone());
+
+        assert_eq!(owner_of::<T>(DEFAULT_NAMESPACE, &claim), Some(new_owner));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn force_revoke_claim(l: Linear<1, { T::MaxClaimLength::get() }>) -> Result<(), BenchmarkError> {
+        let origin = T::ForceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let owner = funded_account::<T>("owner", 0);
+        let claim = create_max_claim::<T>(&owner, l)?;
+        make_joint::<T>(&owner, &claim, T::MaxCoOwners::get())?;
+        challenge::<T>(&funded_account::<T>("challenger", 0), &claim)?;
+
+        #[extrinsic_call]
+        force_revoke_claim(origin as T::RuntimeOrigin, claim.clone(), RevocationReason::Compromised);
+
+        assert!(Tombstones::<T>::contains_key(DEFAULT_NAMESPACE, &claim));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn challenge_claim(l: Linear<1, { T::MaxClaimLength::get() }>) -> Result<(), BenchmarkError> {
+        let owner = funded_account::<T>("owner", 0);
+        let caller: T::AccountId = whitelisted_caller();
+        fund::<T>(&caller);
+        let claim = create_max_claim::<T>(&owner, l)?;
+
+        #[extrinsic_call]
+        challenge_claim(RawOrigin::Signed(caller), claim.clone());
+
+        assert!(Challenges::<T>::contains_key(DEFAULT_NAMESPACE, &claim));
+        Ok(())
+    }
+
+    // 质疑成立并将存证转给质疑人
+    #[benchmark]
+    fn resolve_challenge(l: Linear<1, { T::MaxClaimLength::get() }>) -> Result<(), BenchmarkError> {
+        let origin = T::ArbiterOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let owner = funded_account::<T>("owner", 0);
+        let challenger = funded_account::<T>("challenger", 0);
+        let claim = create_max_claim::<T>(&owner, l)?;
+        offer::<T>(&owner, &claim, &account("recipient", 0, SEED))?;
+        challenge::<T>(&challenger, &claim)?;
+
+        #[extrinsic_call]
+        resolve_challenge(
+            origin as T::RuntimeOrigin,
+            claim.clone(),
+            Verdict::Upheld { reassign_to: Some(challenger.clone()) },
+        );
+
+        assert_eq!(owner_of::<T>(DEFAULT_NAMESPACE, &claim), Some(challenger));
+        Ok(())
+    }
+
+    // 在 on_initialize 中退还一项到期质疑的保证金
+    #[benchmark]
+    fn expire_challenge() -> Result<(), BenchmarkError> {
+        let owner = funded_account::<T>("owner", 0);
+        let claim = create_max_claim::<T>(&owner, T::MaxClaimLength::get())?;
+        challenge::<T>(&funded_account::<T>("challenger", 0), &claim)?;
+        let expires_at = Challenges::<T>::get(DEFAULT_NAMESPACE, &claim)
+            .map(|challenge| challenge.expires_at)
+            .ok_or(BenchmarkError::Stop("the claim is not challenged"))?;
+
+        #[block]
+        {
+            PoeModule::<T>::expire_challenges(expires_at);
+        }
+
+        assert!(!Challenges::<T>::contains_key(DEFAULT_NAMESPACE, &claim));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn create_claim_signed(l: Linear<1, { T::MaxClaimLength::get() }>) {
+        let caller: T::AccountId = whitelisted_caller();
+        let public = T::BenchmarkHelper::create_public();
+        let owner = public.clone().into_account();
+        fund::<T>(&owner);
+        let claim = claim_of_len::<T>(l);
+        let deadline: BlockNumberFor<T> = 1000u32.into();
+        let payload = PoeModule::<T>::signed_claim_payload(&claim, &owner, 0, deadline);
+        let signature = T::BenchmarkHelper::sign(&public, &payload);
+
+        #[extrinsic_call]
+        create_claim_signed(RawOrigin::Signed(caller), claim.clone(), owner.clone(), signature, deadline);
+
+        assert_eq!(owner_of::<T>(DEFAULT_NAMESPACE, &claim), Some(owner));
+    }
+
+    #[benchmark]
+    fn create_namespace() -> Result<(), BenchmarkError> {
+        let origin = T::ForceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let admin: T::AccountId = account("admin", 0, SEED);
+        let namespace = NextNamespaceId::<T>::get();
+
+        #[extrinsic_call]
+        create_namespace(origin as T::RuntimeOrigin, admin, NamespacePolicy::AllowList);
+
+        assert!(Namespaces::<T>::contains_key(namespace));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn set_namespace_admin() {
+        let caller: T::AccountId = whitelisted_caller();
+        let admin: T::AccountId = account("admin", 0, SEED);
+        let namespace = add_namespace::<T>(&caller, NamespacePolicy::AllowList);
+
+        #[extrinsic_call]
+        set_namespace_admin(RawOrigin::Signed(caller), namespace, admin.clone());
+
+        assert_eq!(Namespaces::<T>::get(namespace).map(|details| details.admin), Some(admin));
+    }
+
+    #[benchmark]
+    fn set_namespace_policy() {
+        let caller: T::AccountId = whitelisted_caller();
+        let namespace = add_namespace::<T>(&caller, NamespacePolicy::AllowList);
+
+        #[extrinsic_call]
+        set_namespace_policy(RawOrigin::Signed(caller), namespace, NamespacePolicy::Open);
+
+        assert_eq!(
+            Namespaces::<T>::get(namespace).map(|details| details.policy),
+            Some(NamespacePolicy::Open)
+        );
+    }
+
+    #[benchmark]
+    fn set_namespace_member() {
+        let caller: T::AccountId = whitelisted_caller();
+        let member: T::AccountId = account("member", 0, SEED);
+        let namespace = add_namespace::<T>(&caller, NamespacePolicy::AllowList);
+
+        #[extrinsic_call]
+        set_namespace_member(RawOrigin::Signed(caller), namespace, member.clone(), true);
+
+        assert!(NamespaceMembers::<T>::contains_key(namespace, &member));
+    }
+
+    // 调用者不是管理员，需要查询名单
+    #[benchmark]
+    fn create_claim_in(l: Linear<1, { T::MaxClaimLength::get() }>) {
+        let caller: T::AccountId = whitelisted_caller();
+        fund::<T>(&caller);
+        let namespace = add_namespace::<T>(&account("admin", 0, SEED), NamespacePolicy::AllowList);
+        NamespaceMembers::<T>::insert(namespace, &caller, ());
+        let claim = claim_of_len::<T>(l);
+
+        #[extrinsic_call]
+        create_claim_in(RawOrigin::Signed(caller.clone()), namespace, claim.clone());
+
+        assert_eq!(owner_of::<T>(namespace, &claim), Some(caller));
+    }
+
+    // 由命名空间管理员撤销他人的存证
+    #[benchmark]
+    fn revoke_claim_in(l: Linear<1, { T::MaxClaimLength::get() }>) -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+        let owner = funded_account::<T>("owner", 0);
+        let namespace = add_namespace::<T>(&caller, NamespacePolicy::Open);
+        let claim = claim_of_len::<T>(l);
+        PoeModule::<T>::create_claim_in(RawOrigin::Signed(owner).into(), namespace, claim.clone())
+            .map_err(|_| BenchmarkError::Stop("failed to create the claim"))?;
+
+        #[extrinsic_call]
+        revoke_claim_in(RawOrigin::Signed(caller), namespace, claim.clone(), RevocationReason::Compromised);
+
+        assert!(Tombstones::<T>::contains_key(namespace, &claim));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn transfer_claim_in(l: Linear<1, { T::MaxClaimLength::get() }>) -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+        let new_owner = funded_account::<T>("new_owner", 0);
+        fund::<T>(&caller);
+        let namespace = add_namespace::<T>(&account("admin", 0, SEED), NamespacePolicy::Open);
+        let claim = claim_of_len::<T>(l);
+        PoeModule::<T>::create_claim_in(RawOrigin::Signed(caller.clone()).into(), namespace, claim.clone())
+            .map_err(|_| BenchmarkError::Stop("failed to create the claim"))?;
+
+        #[extrinsic_call]
+        transfer_claim_in(RawOrigin::Signed(caller), namespace, claim.clone(), new_owner.clone());
+
+        assert_eq!(owner_of::<T>(namespace, &claim), Some(new_owner));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn lock_claim(l: Linear<1, { T::MaxClaimLength::get() }>) -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+        let beneficiary: T::AccountId = account("beneficiary", 0, SEED);
+        fund::<T>(&caller);
+        let claim = create_max_claim::<T>(&caller, l)?;
+
+        #[extrinsic_call]
+        lock_claim(RawOrigin::Signed(caller), claim.clone(), 1000u32.into(), Some(beneficiary));
+
+        assert!(matches!(
+            Proofs::<T>::get(DEFAULT_NAMESPACE, &claim).map(|info| info.status),
+            Some(ClaimStatus::Locked { .. })
+        ));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn unlock_claim(l: Linear<1, { T::MaxClaimLength::get() }>) -> Result<(), BenchmarkError> {
+        let owner = funded_account::<T>("owner", 0);
+        let caller: T::AccountId = whitelisted_caller();
+        let claim = create_max_claim::<T>(&owner, l)?;
+        PoeModule::<T>::lock_claim(
+            RawOrigin::Signed(owner).into(),
+            claim.clone(),
+            1000u32.into(),
+            Some(caller.clone()),
+        )
+        .map_err(|_| BenchmarkError::Stop("failed to lock the claim"))?;
+
+        #[extrinsic_call]
+        unlock_claim(RawOrigin::Signed(caller), claim.clone());
+
+        assert_eq!(
+            Proofs::<T>::get(DEFAULT_NAMESPACE, &claim).map(|info| info.status),
+            Some(ClaimStatus::Active)
+        );
+        Ok(())
+    }
+
+    #[benchmark]
+    fn revoke_hashed_claim() -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+        fund::<T>(&caller);
+        let hash = create_hashed_claim::<T>(&caller)?;
+
+        #[extrinsic_call]
+        revoke_hashed_claim(RawOrigin::Signed(caller), hash);
+
+        assert!(!HashedClaims::<T>::contains_key(hash));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn transfer_hashed_claim() -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+        let new_owner = funded_account::<T>("new_owner", 0);
+        fund::<T>(&caller);
+        let hash = create_hashed_claim::<T>(&caller)?;
+
+        #[extrinsic_call]
+        transfer_hashed_claim(RawOrigin::Signed(caller), hash, new_owner.clone());
+
+        assert_eq!(HashedClaims::<T>::get(hash).map(|claim| claim.owner), Some(new_owner));
+        Ok(())
+    }
+
+    // 最坏情况：上一个窗口已结束，需要在队列中移动签名人
+    #[benchmark]
+    fn check_rate_limit() -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+        let window = PoeModule::<T>::rate_window_after(&caller, 1)
+            .ok_or(BenchmarkError::Weightless)?;
+        PoeModule::<T>::put_rate_window(&caller, window);
+        let now = frame_system::Pallet::<T>::block_number().saturating_add(T::WindowLength::get());
+        frame_system::Pallet::<T>::set_block_number(now);
+
+        #[block]
+        {
+            let window = PoeModule::<T>::rate_window_after(&caller, 1)
+                .ok_or(BenchmarkError::Stop("the window is full"))?;
+            PoeModule::<T>::put_rate_window(&caller, window);
+        }
 
-        assert_eq!(Something::<T>::get(), Some(101u32));
+        assert_eq!(ClaimRateWindows::<T>::get(&caller), Some((now, 1)));
+        Ok(())
     }
 
-    impl_benchmark_test_suite!(Template, crate::mock::new_test_ext(), crate::mock::Test);
+    impl_benchmark_test_suite!(PoeModule, crate::mock::new_test_ext(), crate::mock::Test);
 }
@@ -0,0 +1,130 @@
+//! Transaction extensions of the PoE pallet.
+
+use crate::{Call, Config, Pallet};
+use codec::{Decode, Encode};
+use core::marker::PhantomData;
+use frame_support::{
+    sp_runtime::{
+        traits::{DispatchInfoOf, Saturating, SignedExtension},
+        transaction_validity::{
+            InvalidTransaction, TransactionValidity, TransactionValidityError, ValidTransaction,
+        },
+        SaturatedConversion,
+    },
+    traits::{Get, IsSubType},
+};
+use frame_system::pallet_prelude::BlockNumberFor;
+use scale_info::TypeInfo;
+
+/// The [`InvalidTransaction::Custom`] code of a transaction that would take its signer over the
+/// claim quota of the current window.
+pub const RATE_LIMITED: u8 = 1;
+
+/// Rejects PoE calls that would take their signer over `MaxClaimsPerAccountPerWindow` new claims
+/// in the current window of `WindowLength` blocks. The quota is checked when the transaction
+/// enters the pool, and checked again and charged just before it is dispatched.
+///
+/// A valid transaction gets the quota it leaves in the window as priority, so that accounts close
+/// to their quota yield to the others, and lives in the pool until the window ends, when it is
+/// revalidated against the next window. The order of the transactions of one account is left to
+/// `CheckNonce`.
+///
+/// The storage accessed in `pre_dispatch` is weighed by `WeightInfo::check_rate_limit`, which is
+/// included in the weight of every call that creates claims.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+pub struct CheckClaimRateLimit<T: Config + Send + Sync>(PhantomData<T>);
+
+impl<T: Config + Send + Sync> CheckClaimRateLimit<T> {
+    /// Create a new extension.
+    pub fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<T: Config + Send + Sync> Default for CheckClaimRateLimit<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Config + Send + Sync> core::fmt::Debug for CheckClaimRateLimit<T> {
+    #[cfg(feature = "std")]
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "CheckClaimRateLimit")
+    }
+
+    #[cfg(not(feature = "std"))]
+    fn fmt(&self, _: &mut core::fmt::Formatter) -> core::fmt::Result {
+        Ok(())
+    }
+}
+
+impl<T: Config + Send + Sync> CheckClaimRateLimit<T>
+where
+    <T as frame_system::Config>::RuntimeCall: IsSubType<Call<T>>,
+{
+    /// The rate-limit window of `who` once `call` is dispatched, or `None` if `call` creates no
+    /// claims.
+    fn window_after(
+        who: &T::AccountId,
+        call: &<T as frame_system::Config>::RuntimeCall,
+    ) -> Result<Option<(BlockNumberFor<T>, u32)>, TransactionValidityError> {
+        let claims = call.is_sub_type().map_or(0, Pallet::<T>::claims_created_by);
+        if claims == 0 {
+            return Ok(None);
+        }
+        Pallet::<T>::rate_window_after(who, claims)
+            .map(Some)
+            .ok_or(InvalidTransaction::Custom(RATE_LIMITED).into())
+    }
+}
+
+impl<T: Config + Send + Sync> SignedExtension for CheckClaimRateLimit<T>
+where
+    <T as frame_system::Config>::RuntimeCall: IsSubType<Call<T>>,
+{
+    const IDENTIFIER: &'static str = "CheckClaimRateLimit";
+    type AccountId = T::AccountId;
+    type Call = <T as frame_system::Config>::RuntimeCall;
+    type AdditionalSigned = ();
+    type Pre = ();
+
+    fn additional_signed(&self) -> Result<(), TransactionValidityError> {
+        Ok(())
+    }
+
+    fn validate(
+        &self,
+        who: &Self::AccountId,
+        call: &Self::Call,
+        _info: &DispatchInfoOf<Self::Call>,
+        _len: usize,
+    ) -> TransactionValidity {
+        let Some((start, used)) = Self::window_after(who, call)? else {
+            return Ok(ValidTransaction::default());
+        };
+        let now = frame_system::Pallet::<T>::block_number();
+        let ends_at = start.saturating_add(T::WindowLength::get());
+        Ok(ValidTransaction {
+            priority: T::MaxClaimsPerAccountPerWindow::get().saturating_sub(used).into(),
+            // 窗口结束后重新校验，至少保留一个区块
+            longevity: ends_at.saturating_sub(now).saturated_into::<u64>().max(1),
+            ..Default::default()
+        })
+    }
+
+    fn pre_dispatch(
+        self,
+        who: &Self::AccountId,
+        call: &Self::Call,
+        _info: &DispatchInfoOf<Self::Call>,
+        _len: usize,
+    ) -> Result<(), TransactionValidityError> {
+        // 在派发前计入配额，即使调用失败也占用名额
+        if let Some(window) = Self::window_after(who, call)? {
+            Pallet::<T>::put_rate_window(who, window);
+        }
+        Ok(())
+    }
+}
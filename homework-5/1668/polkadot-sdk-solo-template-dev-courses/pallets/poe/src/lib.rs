@@ -39,9 +39,22 @@
 // We make sure this pallet uses `no_std` for compiling to Wasm.
 #![cfg_attr(not(feature = "std"), no_std)]
 
+extern crate alloc;
+
 // Re-export pallet items so that they can be accessed from the crate namespace.
 pub use pallet::*;
 
+// Types shared with the runtime API and RPC.
+mod types;
+pub use types::*;
+
+// Storage migrations between versions of this pallet.
+pub mod migrations;
+
+// Transaction extensions that guard the calls of this pallet.
+mod extensions;
+pub use extensions::*;
+
 // FRAME pallets require their own "mock runtimes" to be able to run unit tests. This module
 // contains a mock runtime specific for testing this pallet's functionality.
 #[cfg(test)]
@@ -55,24 +68,51 @@ mod tests;
 // Every callable function or "dispatchable" a pallet exposes must have weight values that correctly
 // estimate a dispatchable's execution time. The benchmarking module is used to calculate weights
 // for each dispatchable and generates this pallet's weight.rs file. Learn more about benchmarking here: https://docs.substrate.io/test/benchmark/
-/* 
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
 pub mod weights;
 pub use weights::*;
-*/
 
 // All pallet logic is defined in its own module and must be annotated by the `pallet` attribute.
 #[frame_support::pallet]
 pub mod pallet {
     // Import various useful types required by all FRAME pallets.
     use super::*;
-    use frame_support::pallet_prelude::*;
+    use alloc::vec::Vec;
+    use frame_support::{
+        pallet_prelude::*,
+        dispatch::WithPostDispatchInfo,
+        storage::with_storage_layer,
+        sp_runtime::{
+            traits::{Hash, IdentifyAccount, Saturating, Verify, Zero},
+            SaturatedConversion,
+        },
+        traits::{
+            fungible::{Inspect, Mutate, MutateHold},
+            tokens::{Fortitude, Precision},
+            UnixTime,
+        },
+        weights::WeightMeter,
+    };
     use frame_system::pallet_prelude::*;
+    #[cfg(feature = "runtime-benchmarks")]
+    use frame_support::sp_runtime::{KeyTypeId, MultiSignature, MultiSigner};
+
+    /// The balance type of the currency used for claim deposits.
+    pub type BalanceOf<T> =
+        <<T as Config>::Currency as Inspect<<T as frame_system::Config>::AccountId>>::Balance;
 
     // The `Pallet` struct serves as a placeholder to implement traits, methods and dispatchables
     // (`Call`s) in this pallet.
+    /// The in-code storage version.
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(3);
+
+    /// The prefix of the payload signed for `create_claim_signed`, so that the signature cannot be
+    /// replayed as a signature over anything else.
+    pub const SIGNED_CLAIM_DOMAIN: &[u8] = b"pallet-poe/create_claim_signed";
+
     #[pallet::pallet]
+    #[pallet::storage_version(STORAGE_VERSION)]
     pub struct Pallet<T>(_);
 
     /// The pallet's configuration trait.
@@ -86,13 +126,369 @@ pub mod pallet {
         type MaxClaimLength: Get<u32>;
         /// The overarching runtime event type.
         type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
-    
+        /// The overarching hold reason.
+        type RuntimeHoldReason: From<HoldReason>;
+        /// Weight information for the extrinsics in this pallet.
+        type WeightInfo: WeightInfo;
+        /// The wall-clock time recorded with claims, usually `pallet_timestamp`.
+        type UnixTime: UnixTime;
+        /// The currency used to hold the storage deposit of a claim.
+        type Currency: Mutate<Self::AccountId>
+            + MutateHold<Self::AccountId, Reason = Self::RuntimeHoldReason>;
+        /// The base deposit held for every claim.
+        #[pallet::constant]
+        type ClaimDeposit: Get<BalanceOf<Self>>;
+        /// The additional deposit held per byte of claim.
+        #[pallet::constant]
+        type DepositPerByte: Get<BalanceOf<Self>>;
+        /// The maximum number of active claims a single account may own.
+        #[pallet::constant]
+        type MaxClaimsPerOwner: Get<u32>;
+        /// The number of blocks after which a pending transfer offer can no longer be accepted.
+        #[pallet::constant]
+        type OfferTimeout: Get<BlockNumberFor<Self>>;
+        /// Whether the one-shot `transfer_claim` is available next to the offer / accept flow.
+        #[pallet::constant]
+        type AllowDirectTransfer: Get<bool>;
+        /// The maximum length of the content hashed by `create_claim_from_content`.
+        #[pallet::constant]
+        type MaxContentLength: Get<u32>;
+        /// The maximum number of items in a batch call. Must not exceed
+        /// `MaxClaimsPerAccountPerWindow`.
+        #[pallet::constant]
+        type MaxBatchSize: Get<u32>;
+        /// The origin allowed to create, transfer and revoke claims regardless of ownership,
+        /// e.g. to enforce a court order or recover a claim after a stolen key.
+        type ForceOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+        /// The origin that resolves challenges raised with `challenge_claim`.
+        type ArbiterOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+        /// The bond held from a challenger until the challenge is resolved or expires.
+        #[pallet::constant]
+        type ChallengeBond: Get<BalanceOf<Self>>;
+        /// The number of blocks after which an unresolved challenge expires and its bond is
+        /// returned.
+        #[pallet::constant]
+        type ChallengePeriod: Get<BlockNumberFor<Self>>;
+        /// Who may register a claim again after it has been revoked or has expired.
+        #[pallet::constant]
+        type Reregistration: Get<ReregistrationPolicy>;
+        /// The number of provenance records kept per claim. Older records are dropped first.
+        #[pallet::constant]
+        type MaxHistoryLength: Get<u32>;
+        /// The maximum number of co-owners of a jointly owned claim.
+        #[pallet::constant]
+        type MaxCoOwners: Get<u32>;
+        /// The maximum length of the MIME type in claim metadata.
+        #[pallet::constant]
+        type MaxContentTypeLength: Get<u32>;
+        /// The maximum length of the off-chain URI in claim metadata.
+        #[pallet::constant]
+        type MaxUriLength: Get<u32>;
+        /// The maximum length of the description in claim metadata.
+        #[pallet::constant]
+        type MaxDescriptionLength: Get<u32>;
+        /// The signature with which an owner authorises `create_claim_signed` off-chain.
+        type OffchainSignature: Verify<Signer = Self::OffchainPublic> + Parameter;
+        /// The public key that verifies an [`Config::OffchainSignature`].
+        type OffchainPublic: IdentifyAccount<AccountId = Self::AccountId> + Clone;
+        /// The number of claims an account may create per window, enforced by
+        /// [`CheckClaimRateLimit`].
+        #[pallet::constant]
+        type MaxClaimsPerAccountPerWindow: Get<u32>;
+        /// The length of a rate-limit window, in blocks.
+        #[pallet::constant]
+        type WindowLength: Get<BlockNumberFor<Self>>;
+        /// Creates the keys and signatures used by the benchmarks of `create_claim_signed`.
+        #[cfg(feature = "runtime-benchmarks")]
+        type BenchmarkHelper: BenchmarkHelper<Self::OffchainPublic, Self::OffchainSignature>;
+    }
+
+    /// Creates the keys and signatures used by the benchmarks of `create_claim_signed`.
+    #[cfg(feature = "runtime-benchmarks")]
+    pub trait BenchmarkHelper<Public, Signature> {
+        /// Generate a new key pair and return its public key.
+        fn create_public() -> Public;
+        /// Sign `message` with the key pair of `public`.
+        fn sign(public: &Public, message: &[u8]) -> Signature;
+    }
+
+    /// Signs with sr25519 keys kept in the keystore of the benchmarking host.
+    #[cfg(feature = "runtime-benchmarks")]
+    impl BenchmarkHelper<MultiSigner, MultiSignature> for () {
+        fn create_public() -> MultiSigner {
+            sp_io::crypto::sr25519_generate(BENCHMARK_KEY_TYPE, None).into()
+        }
+
+        fn sign(public: &MultiSigner, message: &[u8]) -> MultiSignature {
+            let MultiSigner::Sr25519(public) = public else {
+                panic!("create_public only generates sr25519 keys");
+            };
+            sp_io::crypto::sr25519_sign(BENCHMARK_KEY_TYPE, public, message)
+                .expect("the key pair was generated by create_public")
+                .into()
+        }
+    }
+
+    /// The keystore key type of the keys generated by [`BenchmarkHelper::create_public`].
+    #[cfg(feature = "runtime-benchmarks")]
+    const BENCHMARK_KEY_TYPE: KeyTypeId = KeyTypeId(*b"poe!");
+
+    /// A reason for the pallet placing a hold on funds.
+    #[pallet::composite_enum]
+    pub enum HoldReason {
+        /// The funds are held as the storage deposit of a claim.
+        ClaimDeposit,
+        /// The funds are held as the bond of a pending challenge.
+        ChallengeBond,
+    }
+
+    /// The claims, keyed by namespace and the claimed bytes. The calls without a namespace use
+    /// [`DEFAULT_NAMESPACE`]. The other per-claim storage items are keyed the same way, so the
+    /// same bytes claimed in two namespaces never share any state.
+    #[pallet::storage]
+    pub type Proofs<T:Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        NamespaceId,
+        Blake2_128Concat,
+        BoundedVec<u8, T::MaxClaimLength>,
+        ClaimInfo<T::AccountId, BlockNumberFor<T>>,
+    >;
+
+    /// The deposit currently held from the owner of an active claim, keyed like [`Proofs`].
+    #[pallet::storage]
+    pub type Deposits<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        NamespaceId,
+        Blake2_128Concat,
+        BoundedVec<u8, T::MaxClaimLength>,
+        BalanceOf<T>,
+    >;
+
+    /// The namespaces created with `create_namespace`. [`DEFAULT_NAMESPACE`] is not listed.
+    #[pallet::storage]
+    pub type Namespaces<T: Config> =
+        StorageMap<_, Twox64Concat, NamespaceId, Namespace<T::AccountId>>;
+
+    /// The accounts allowed to create claims in an allow-list namespace.
+    #[pallet::storage]
+    pub type NamespaceMembers<T: Config> =
+        StorageDoubleMap<_, Twox64Concat, NamespaceId, Blake2_128Concat, T::AccountId, ()>;
+
+    #[pallet::type_value]
+    pub fn FirstNamespaceId() -> NamespaceId {
+        DEFAULT_NAMESPACE + 1
     }
+
+    /// The id of the next namespace to be created.
+    #[pallet::storage]
+    pub type NextNamespaceId<T: Config> =
+        StorageValue<_, NamespaceId, ValueQuery, FirstNamespaceId>;
+
+    /// The block from which a time-limited claim is no longer valid.
+    #[pallet::storage]
+    pub type Expiries<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        NamespaceId,
+        Blake2_128Concat,
+        BoundedVec<u8, T::MaxClaimLength>,
+        BlockNumberFor<T>,
+    >;
+
+    /// Time-limited claims indexed by their expiry block, so that they can be purged in order.
+    #[pallet::storage]
+    pub type ExpiryQueue<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        BlockNumberFor<T>,
+        Blake2_128Concat,
+        (NamespaceId, BoundedVec<u8, T::MaxClaimLength>),
+        (),
+    >;
+
+    /// The active claims owned by each account, so that they can be enumerated.
+    #[pallet::storage]
+    pub type ClaimsByOwner<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Blake2_128Concat,
+        (NamespaceId, BoundedVec<u8, T::MaxClaimLength>),
+        (),
+    >;
+
+    /// The number of entries of each account in [`ClaimsByOwner`].
+    #[pallet::storage]
+    pub type OwnerClaimCount<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+    /// Transfer offers waiting to be accepted by their recipient.
+    #[pallet::storage]
+    pub type PendingTransfers<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        NamespaceId,
+        Blake2_128Concat,
+        BoundedVec<u8, T::MaxClaimLength>,
+        PendingTransfer<T::AccountId, BlockNumberFor<T>>,
+    >;
+
+    /// Pending transfer offers indexed by the block in which they expire, so that expired offers
+    /// can be removed in order.
+    #[pallet::storage]
+    pub type OfferQueue<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        BlockNumberFor<T>,
+        Blake2_128Concat,
+        (NamespaceId, BoundedVec<u8, T::MaxClaimLength>),
+        (),
+    >;
+
+    /// The earliest block whose expired offers may not have been removed yet.
+    #[pallet::storage]
+    pub type NextOfferCheck<T: Config> = StorageValue<_, BlockNumberFor<T>>;
+
+    /// The co-owners and approval threshold of jointly owned claims.
+    #[pallet::storage]
+    pub type CoOwners<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        NamespaceId,
+        Blake2_128Concat,
+        BoundedVec<u8, T::MaxClaimLength>,
+        JointOwnership<T>,
+    >;
+
+    /// The actions being approved by the co-owners of a jointly owned claim, keyed by
+    /// namespace, claim and the hash of the action. Each co-owner backs at most one action of a
+    /// claim, so a claim has at most `MaxCoOwners` entries.
+    #[pallet::storage]
+    pub type Approvals<T: Config> = StorageNMap<
+        _,
+        (
+            NMapKey<Twox64Concat, NamespaceId>,
+            NMapKey<Blake2_128Concat, BoundedVec<u8, T::MaxClaimLength>>,
+            NMapKey<Identity, T::Hash>,
+        ),
+        PendingApproval<T>,
+    >;
+
+    /// The metadata attached to each claim.
+    #[pallet::storage]
+    pub type Metadata<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        NamespaceId,
+        Blake2_128Concat,
+        BoundedVec<u8, T::MaxClaimLength>,
+        ClaimMetadata<T>,
+    >;
+
+    /// Merkle roots anchored with `anchor_root`. The key is already a hash, so the `Identity`
+    /// hasher is used.
+    #[pallet::storage]
+    pub type Roots<T: Config> =
+        StorageMap<_, Identity, T::Hash, AnchoredRoot<T::AccountId, BlockNumberFor<T>, BalanceOf<T>>>;
+
+    /// Claims created from content with `create_claim_from_content`, keyed by the content hash
+    /// computed with `T::Hashing`. The key is already a hash, so the `Identity` hasher is used.
+    #[pallet::storage]
+    pub type HashedClaims<T: Config> =
+        StorageMap<_, Identity, T::Hash, HashedClaim<T::AccountId, BlockNumberFor<T>, BalanceOf<T>>>;
+
+    /// The most recent ownership changes of each claim, oldest first. The history outlives the
+    /// claim: it is kept when the claim is revoked or purged after expiring.
+    #[pallet::storage]
+    pub type ClaimHistory<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        NamespaceId,
+        Blake2_128Concat,
+        BoundedVec<u8, T::MaxClaimLength>,
+        BoundedVec<ProvenanceRecord<T::AccountId, BlockNumberFor<T>>, T::MaxHistoryLength>,
+        ValueQuery,
+    >;
+
+    /// Why and by whom each revoked or expired claim was revoked.
+    #[pallet::storage]
+    pub type Tombstones<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        NamespaceId,
+        Blake2_128Concat,
+        BoundedVec<u8, T::MaxClaimLength>,
+        Tombstone<T::AccountId, BlockNumberFor<T>>,
+    >;
+
+    /// The account approved with `approve` to transfer or revoke a single claim.
+    #[pallet::storage]
+    pub type ClaimOperators<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        NamespaceId,
+        Blake2_128Concat,
+        BoundedVec<u8, T::MaxClaimLength>,
+        T::AccountId,
+    >;
+
+    /// Accounts approved with `set_operator` to manage every claim of an owner, keyed by owner
+    /// and operator.
+    #[pallet::storage]
+    pub type Operators<T: Config> =
+        StorageDoubleMap<_, Blake2_128Concat, T::AccountId, Blake2_128Concat, T::AccountId, ()>;
+
+    /// The pending challenge of each disputed claim. A disputed claim cannot be transferred or
+    /// revoked.
+    #[pallet::storage]
+    pub type Challenges<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        NamespaceId,
+        Blake2_128Concat,
+        BoundedVec<u8, T::MaxClaimLength>,
+        Challenge<T::AccountId, BalanceOf<T>, BlockNumberFor<T>>,
+    >;
+
+    /// Pending challenges keyed by the block in which they expire.
+    #[pallet::storage]
+    pub type ChallengeQueue<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        BlockNumberFor<T>,
+        Blake2_128Concat,
+        (NamespaceId, BoundedVec<u8, T::MaxClaimLength>),
+        (),
+    >;
+
+    /// The nonce that the next `create_claim_signed` payload of each owner must carry.
+    #[pallet::storage]
+    pub type SignedClaimNonces<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, u64, ValueQuery>;
+
+    /// The start of the current rate-limit window of each account and the number of claims it
+    /// has created in it.
+    #[pallet::storage]
+    pub type ClaimRateWindows<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, (BlockNumberFor<T>, u32)>;
+
+    /// The accounts in [`ClaimRateWindows`] indexed by the block in which their window ends, so
+    /// that windows that have run out can be removed in order.
+    #[pallet::storage]
+    pub type RateWindowQueue<T: Config> =
+        StorageDoubleMap<_, Twox64Concat, BlockNumberFor<T>, Blake2_128Concat, T::AccountId, ()>;
+
+    /// The earliest block whose ended rate-limit windows may not have been removed yet.
+    #[pallet::storage]
+    pub type NextRateWindowCheck<T: Config> = StorageValue<_, BlockNumberFor<T>>;
+
+    /// The number of entries in [`Proofs`].
+    #[pallet::storage]
+    pub type ClaimCount<T: Config> = StorageValue<_, u64, ValueQuery>;
+
+    /// The earliest expiry block whose claims may not have been purged yet.
     #[pallet::storage]
-    pub type Proofs<T:Config> = 
-        StorageMap<_, Blake2_128Concat, BoundedVec<u8,T::MaxClaimLength>, 
-        (T::AccountId, BlockNumberFor<T>,
-        bool)>;
+    pub type NextExpiryCheck<T: Config> = StorageValue<_, BlockNumberFor<T>>;
 
     /// Events that functions in this pallet can emit.
     ///
@@ -115,15 +511,222 @@ pub mod pallet {
             //who: T::AccountId,
             owner:T::AccountId, 
             claim:BoundedVec<u8, T::MaxClaimLength>,
+            metadata: Option<ClaimMetadata<T>>,
+            /// The time of registration, in milliseconds since the Unix epoch.
+            timestamp: Moment,
         },
         ClaimRevoked{ 
             owner: T::AccountId,
-            claim: BoundedVec<u8, T::MaxClaimLength> 
+            claim: BoundedVec<u8, T::MaxClaimLength>,
+            reason: RevocationReason,
         }, 
         ClaimTransferred { 
             old_owner: T::AccountId, 
             new_owner: T::AccountId, 
-            claim: BoundedVec<u8, T::MaxClaimLength> 
+            claim: BoundedVec<u8, T::MaxClaimLength>,
+            /// The time of the transfer, in milliseconds since the Unix epoch.
+            timestamp: Moment,
+        },
+        /// The owner of a claim has replaced its metadata.
+        ClaimMetadataUpdated {
+            owner: T::AccountId,
+            claim: BoundedVec<u8, T::MaxClaimLength>,
+            metadata: ClaimMetadata<T>,
+        },
+        /// The lifetime of a claim has been extended.
+        ClaimRenewed {
+            owner: T::AccountId,
+            claim: BoundedVec<u8, T::MaxClaimLength>,
+            expires_at: BlockNumberFor<T>,
+        },
+        /// The owner of a claim has offered to transfer it.
+        ClaimOffered {
+            owner: T::AccountId,
+            to: T::AccountId,
+            claim: BoundedVec<u8, T::MaxClaimLength>,
+            expires_at: BlockNumberFor<T>,
+        },
+        /// The recipient of a transfer offer has accepted it.
+        OfferAccepted {
+            old_owner: T::AccountId,
+            new_owner: T::AccountId,
+            claim: BoundedVec<u8, T::MaxClaimLength>,
+        },
+        /// A transfer offer has been withdrawn or declined.
+        OfferCancelled {
+            to: T::AccountId,
+            claim: BoundedVec<u8, T::MaxClaimLength>,
+        },
+        /// A transfer offer passed its deadline without being accepted and has been removed.
+        OfferExpired {
+            to: T::AccountId,
+            claim: BoundedVec<u8, T::MaxClaimLength>,
+        },
+        /// A claim has become jointly owned.
+        CoOwnersSet {
+            claim: BoundedVec<u8, T::MaxClaimLength>,
+            co_owners: BoundedVec<T::AccountId, T::MaxCoOwners>,
+            threshold: u32,
+        },
+        /// A co-owner has approved an action on a jointly owned claim.
+        ActionApproved {
+            who: T::AccountId,
+            claim: BoundedVec<u8, T::MaxClaimLength>,
+            action: JointAction<T::AccountId, BlockNumberFor<T>>,
+            approvals: u32,
+        },
+        /// An action on a jointly owned claim reached its threshold and has been executed.
+        ActionExecuted {
+            claim: BoundedVec<u8, T::MaxClaimLength>,
+            action: JointAction<T::AccountId, BlockNumberFor<T>>,
+        },
+        /// A batch call has finished. `failures` lists the index and error of every item that
+        /// was skipped in best-effort mode.
+        BatchCompleted {
+            succeeded: u32,
+            failures: Vec<(u32, DispatchError)>,
+        },
+        /// A Merkle root covering a set of documents has been anchored.
+        RootAnchored {
+            owner: T::AccountId,
+            root: T::Hash,
+        },
+        /// An anchored Merkle root has been removed and its deposit released.
+        RootRemoved {
+            owner: T::AccountId,
+            root: T::Hash,
+        },
+        /// A claim has been created from content and stored under the content hash.
+        HashedClaimCreated {
+            owner: T::AccountId,
+            hash: T::Hash,
+            /// The time of registration, in milliseconds since the Unix epoch.
+            timestamp: Moment,
+        },
+        /// A claim created from content has been revoked and its deposit released.
+        HashedClaimRevoked {
+            owner: T::AccountId,
+            hash: T::Hash,
+        },
+        /// A claim created from content has changed owner.
+        HashedClaimTransferred {
+            old_owner: T::AccountId,
+            new_owner: T::AccountId,
+            hash: T::Hash,
+            /// The time of the transfer, in milliseconds since the Unix epoch.
+            timestamp: Moment,
+        },
+        /// An expired claim has been purged and its deposit released.
+        ClaimExpired {
+            owner: T::AccountId,
+            claim: BoundedVec<u8, T::MaxClaimLength>,
+        },
+        /// `ForceOrigin` has created a claim on behalf of `owner`.
+        ClaimForceCreated {
+            owner: T::AccountId,
+            claim: BoundedVec<u8, T::MaxClaimLength>,
+        },
+        /// `ForceOrigin` has transferred a claim without the consent of its owner.
+        ClaimForceTransferred {
+            old_owner: T::AccountId,
+            new_owner: T::AccountId,
+            claim: BoundedVec<u8, T::MaxClaimLength>,
+            /// The time of the transfer, in milliseconds since the Unix epoch.
+            timestamp: Moment,
+        },
+        /// `ForceOrigin` has revoked a claim without the consent of its owner.
+        ClaimForceRevoked {
+            owner: T::AccountId,
+            claim: BoundedVec<u8, T::MaxClaimLength>,
+            reason: RevocationReason,
+        },
+        /// A claim has been challenged and is frozen until the challenge is resolved.
+        ClaimChallenged {
+            challenger: T::AccountId,
+            claim: BoundedVec<u8, T::MaxClaimLength>,
+            expires_at: BlockNumberFor<T>,
+        },
+        /// `ArbiterOrigin` has resolved a challenge.
+        ChallengeResolved {
+            challenger: T::AccountId,
+            claim: BoundedVec<u8, T::MaxClaimLength>,
+            verdict: Verdict<T::AccountId>,
+        },
+        /// A challenge was not resolved in time and its bond has been returned.
+        ChallengeExpired {
+            challenger: T::AccountId,
+            claim: BoundedVec<u8, T::MaxClaimLength>,
+        },
+        /// The account allowed to manage a single claim has been set or cleared.
+        OperatorApproved {
+            owner: T::AccountId,
+            claim: BoundedVec<u8, T::MaxClaimLength>,
+            operator: Option<T::AccountId>,
+        },
+        /// An account has been allowed or disallowed to manage every claim of `owner`.
+        OperatorSet {
+            owner: T::AccountId,
+            operator: T::AccountId,
+            approved: bool,
+        },
+        /// A namespace has been created.
+        NamespaceCreated {
+            namespace: NamespaceId,
+            admin: T::AccountId,
+            policy: NamespacePolicy,
+        },
+        /// The admin of a namespace has changed.
+        NamespaceAdminChanged {
+            namespace: NamespaceId,
+            admin: T::AccountId,
+        },
+        /// The creation policy of a namespace has changed.
+        NamespacePolicyChanged {
+            namespace: NamespaceId,
+            policy: NamespacePolicy,
+        },
+        /// An account has been added to or removed from the allow-list of a namespace.
+        NamespaceMemberSet {
+            namespace: NamespaceId,
+            who: T::AccountId,
+            allowed: bool,
+        },
+        /// A claim has been created in a namespace other than [`DEFAULT_NAMESPACE`].
+        NamespacedClaimCreated {
+            namespace: NamespaceId,
+            owner: T::AccountId,
+            claim: BoundedVec<u8, T::MaxClaimLength>,
+            /// The time of registration, in milliseconds since the Unix epoch.
+            timestamp: Moment,
+        },
+        /// A claim in a namespace has been revoked by its owner, an operator or the namespace
+        /// admin.
+        NamespacedClaimRevoked {
+            namespace: NamespaceId,
+            owner: T::AccountId,
+            claim: BoundedVec<u8, T::MaxClaimLength>,
+            reason: RevocationReason,
+        },
+        /// A claim in a namespace has changed owner.
+        NamespacedClaimTransferred {
+            namespace: NamespaceId,
+            old_owner: T::AccountId,
+            new_owner: T::AccountId,
+            claim: BoundedVec<u8, T::MaxClaimLength>,
+            /// The time of the transfer, in milliseconds since the Unix epoch.
+            timestamp: Moment,
+        },
+        /// The owner has locked a claim against transfer and revocation.
+        ClaimLocked {
+            owner: T::AccountId,
+            claim: BoundedVec<u8, T::MaxClaimLength>,
+            until: BlockNumberFor<T>,
+            beneficiary: Option<T::AccountId>,
+        },
+        /// The lock on a claim has been released.
+        ClaimUnlocked {
+            claim: BoundedVec<u8, T::MaxClaimLength>,
+            by: T::AccountId,
         },
 
 
@@ -148,6 +751,146 @@ pub mod pallet {
         NotProofOwner,
         ProofAlreadyRevoked,
         CannotTransferToSelf,
+        /// The account cannot afford the storage deposit of the claim.
+        InsufficientDeposit,
+        /// The claim was revoked and the re-registration policy does not allow the caller to
+        /// register it again.
+        ReregistrationNotAllowed,
+        /// `Expired` is only recorded by the pallet when a claim runs out.
+        InvalidRevocationReason,
+        /// A claim lifetime or extension of zero blocks was given.
+        InvalidLifetime,
+        /// The claim has passed its expiry block.
+        ProofExpired,
+        /// The claim has no expiry and cannot be renewed.
+        ProofNotExpiring,
+        /// The account already owns `MaxClaimsPerOwner` active claims.
+        TooManyClaims,
+        /// There is no pending transfer offer for the claim.
+        OfferNotExist,
+        /// The caller is neither the owner nor the recipient of the offer.
+        NotOfferParty,
+        /// One-shot transfers are disabled; use `offer_claim` and `accept_claim`.
+        DirectTransferDisabled,
+        /// The claim is jointly owned; use `approve_action` to revoke, transfer or lock it.
+        RequiresApproval,
+        /// The claim is already jointly owned.
+        AlreadyJointlyOwned,
+        /// The co-owner list contains duplicates or does not include the owner.
+        InvalidCoOwners,
+        /// The threshold is zero or larger than the number of co-owners.
+        InvalidThreshold,
+        /// The caller is not a co-owner of the claim.
+        NotCoOwner,
+        /// The caller has already approved this action.
+        AlreadyApproved,
+        /// The Merkle root has already been anchored.
+        RootAlreadyAnchored,
+        /// The Merkle root has not been anchored.
+        RootNotAnchored,
+        /// The caller did not anchor the Merkle root.
+        NotRootOwner,
+        /// The given account does not own the claim.
+        OwnerMismatch,
+        /// An owner cannot be its own operator.
+        OperatorIsOwner,
+        /// The claim already has a pending challenge.
+        AlreadyChallenged,
+        /// The claim has no pending challenge.
+        NotChallenged,
+        /// The claim is disputed and cannot be transferred or revoked until the challenge is
+        /// resolved.
+        ClaimDisputed,
+        /// Owners cannot challenge their own claims.
+        CannotChallengeOwnClaim,
+        /// The challenger cannot afford the challenge bond.
+        InsufficientBond,
+        /// The deadline of a signed claim has passed.
+        SignatureExpired,
+        /// The signature does not match the owner, or was made for another nonce.
+        InvalidSignature,
+        /// The namespace does not exist.
+        NamespaceNotFound,
+        /// Only the namespace admin or `ForceOrigin` may do this.
+        NotNamespaceAdmin,
+        /// The namespace policy does not allow the caller to create claims in it.
+        NotAllowedInNamespace,
+        /// The claim is locked and cannot be transferred or revoked.
+        ClaimLocked,
+        /// The claim is not locked.
+        ClaimNotLocked,
+        /// A lock must end after the current block.
+        InvalidLockPeriod,
+    }
+
+    #[pallet::genesis_config]
+    #[derive(frame_support::DefaultNoBound)]
+    pub struct GenesisConfig<T: Config> {
+        /// Claims that exist from block 0, as `(claim bytes, owner)`. The owner must be endowed
+        /// with enough funds to hold the claim deposit.
+        pub claims: Vec<(Vec<u8>, T::AccountId)>,
+    }
+
+    #[pallet::genesis_build]
+    impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
+        fn build(&self) {
+            for (claim, owner) in &self.claims {
+                let claim: BoundedVec<u8, T::MaxClaimLength> = claim
+                    .clone()
+                    .try_into()
+                    .expect("genesis claim is longer than MaxClaimLength");
+                assert!(!Proofs::<T>::contains_key(DEFAULT_NAMESPACE, &claim), "duplicate genesis claim {:?}", claim);
+                // 创世时尚无时间戳，记录为 0
+                Pallet::<T>::insert_claim(owner.clone(), DEFAULT_NAMESPACE, claim, None, None, 0)
+                    .expect("genesis claim owner must be able to hold the claim deposit");
+            }
+        }
+    }
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        /// Return the bonds of challenges that expire in this block.
+        fn on_initialize(now: BlockNumberFor<T>) -> Weight {
+            Self::expire_challenges(now)
+        }
+
+        /// A batch larger than a rate-limit window could never pass `CheckClaimRateLimit`, and a
+        /// full batch of the longest claims must fit in a single extrinsic.
+        fn integrity_test() {
+            assert!(
+                T::MaxBatchSize::get() <= T::MaxClaimsPerAccountPerWindow::get(),
+                "MaxBatchSize must not exceed MaxClaimsPerAccountPerWindow",
+            );
+
+            let (n, b) = (T::MaxBatchSize::get(), T::MaxBatchSize::get().saturating_mul(T::MaxClaimLength::get()));
+            let max_extrinsic = T::BlockWeights::get()
+                .get(DispatchClass::Normal)
+                .max_extrinsic
+                .unwrap_or(Weight::MAX);
+            for weight in [
+                T::WeightInfo::create_claims(n, 0, b).saturating_add(T::WeightInfo::check_rate_limit()),
+                T::WeightInfo::revoke_claims(n, 0, b),
+                T::WeightInfo::transfer_claims(n, 0, b),
+            ] {
+                assert!(weight.all_lte(max_extrinsic), "a full batch does not fit in an extrinsic");
+            }
+        }
+
+        /// Purge expired claims, then expired transfer offers and ended rate-limit windows, with
+        /// whatever weight is left in the block.
+        fn on_idle(now: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+            let mut consumed = Self::purge_expired(now, remaining_weight);
+            consumed.saturating_accrue(Self::purge_offers(now, remaining_weight.saturating_sub(consumed)));
+            consumed.saturating_accrue(
+                Self::purge_rate_windows(now, remaining_weight.saturating_sub(consumed)),
+            );
+            consumed
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn try_state(_n: BlockNumberFor<T>) -> Result<(), frame_support::sp_runtime::TryRuntimeError> {
+            Self::do_try_state()
+        }
     }
 
     /// The pallet's dispatchable functions ([`Call`]s).
@@ -166,89 +909,2081 @@ pub mod pallet {
     impl<T: Config> Pallet<T> {
      
         #[pallet::call_index(0)]
-        #[pallet::weight({0})]
-        pub fn create_claim(origin: OriginFor<T>,claim:BoundedVec<u8,T::MaxClaimLength>)
-         -> DispatchResult
+        #[pallet::weight(T::WeightInfo::create_claim(T::MaxClaimLength::get())
+            .saturating_add(T::WeightInfo::check_rate_limit()))]
+        pub fn create_claim(
+            origin: OriginFor<T>,
+            claim: BoundedVec<u8, T::MaxClaimLength>,
+            lifetime: Option<BlockNumberFor<T>>,
+            metadata: Option<ClaimMetadata<T>>,
+        ) -> DispatchResultWithPostInfo
          {
             //let  who:<T as Config>::AccountId = ensure_signed(origin)?;
             let  who = ensure_signed(origin)?;
-            ensure!{
-                !Proofs::<T>::contains_key(&claim),
-                Error::<T>::ProofAlreadyExist
-            };
-            Proofs::<T>::insert
-            (
-                &claim,
-                (who.clone(),frame_system::Pallet::<T>::block_number(),true),
-            );
-            // 打印存储内容以便调试
-            
-            Self::deposit_event(Event::ClaimCreated{ owner: who, claim});     
-            Ok(())
+            let len = claim.len() as u32;
+            Self::do_create(who, DEFAULT_NAMESPACE, claim, lifetime, metadata)?;
+            // 按实际长度退还多收的权重
+            Ok(Some(
+                T::WeightInfo::create_claim(len).saturating_add(T::WeightInfo::check_rate_limit()),
+            )
+            .into())
          }
          
          #[pallet::call_index(1)]
-         #[pallet::weight({0})]
+         #[pallet::weight(T::WeightInfo::revoke_claim(T::MaxClaimLength::get()))]
          pub fn revoke_claim(
              origin: OriginFor<T>,
              claim: BoundedVec<u8, T::MaxClaimLength>,
-         ) -> DispatchResult {
+             reason: RevocationReason,
+         ) -> DispatchResultWithPostInfo {
             // 验证调用者身份
             let who = ensure_signed(origin)?;
-         
-            // 确保该 PoE 数据存在
-            ensure!(
-                Proofs::<T>::contains_key(&claim),
-                Error::<T>::ProofNotExist
-            );
-         
-        // 确保调用者是数据的所有者
-            let (owner, _, is_active) = Proofs::<T>::get(&claim).ok_or(Error::<T>::ProofNotExist)?;
-            ensure!(who == owner, Error::<T>::NotProofOwner);
-         
-            // 确保数据当前是有效状态
-            ensure!(is_active, Error::<T>::ProofAlreadyRevoked);
-         
-            // 更新状态为无效
-            Proofs::<T>::insert(&claim, (who.clone(), frame_system::Pallet::<T>::block_number(), false));
-         
-            // 触发撤回事件
-            Self::deposit_event(Event::ClaimRevoked { owner: who, claim });
-         
-            Ok(())
+            let len = claim.len() as u32;
+            Self::try_revoke(who, DEFAULT_NAMESPACE, claim, reason)?;
+            Ok(Some(T::WeightInfo::revoke_claim(len)).into())
         }
     
         #[pallet::call_index(2)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::transfer_claim(T::MaxClaimLength::get()))]
         pub fn transfer_claim(
             origin: OriginFor<T>,
             claim: BoundedVec<u8, T::MaxClaimLength>,
             new_owner: T::AccountId,
-        ) -> DispatchResult {
+        ) -> DispatchResultWithPostInfo {
             // 验证调用者签名
             let sender = ensure_signed(origin)?;
-    
-            // 校验数据是否存在
-            let (current_owner, block_number, _) = Proofs::<T>::get(&claim).ok_or(Error::<T>::ProofNotExist)?;
-    
-            // 确保调用者是当前所有者
-            ensure!(current_owner == sender, Error::<T>::NotProofOwner);
-    
-            // 确保新所有者不同于当前所有者
-            ensure!(current_owner != new_owner, Error::<T>::CannotTransferToSelf);
-    
-            // 更新存储，将所有权转移给新所有者
-            Proofs::<T>::insert(&claim, (new_owner.clone(), block_number, true));
-    
-            // 触发事件
-            Self::deposit_event(Event::ClaimTransferred {
-                old_owner: sender,
-                new_owner,
-                claim,
-            });
-    
-            Ok(())
+            ensure!(T::AllowDirectTransfer::get(), Error::<T>::DirectTransferDisabled);
+            let len = claim.len() as u32;
+            Self::try_transfer(sender, DEFAULT_NAMESPACE, claim, new_owner)?;
+            Ok(Some(T::WeightInfo::transfer_claim(len)).into())
         }
-    }
 
-}
\ No newline at end of file
+        /// Extend the lifetime of a time-limited claim by `extension` blocks.
+        #[pallet::call_index(3)]
+        #[pallet::weight(T::WeightInfo::renew_claim(claim.len() as u32))]
+        pub fn renew_claim(
+            origin: OriginFor<T>,
+            claim: BoundedVec<u8, T::MaxClaimLength>,
+            extension: BlockNumberFor<T>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            let len = claim.len() as u32;
+
+            let ClaimInfo { owner, status, .. } = Proofs::<T>::get(DEFAULT_NAMESPACE, &claim).ok_or(Error::<T>::ProofNotExist)?;
+            ensure!(owner == who, Error::<T>::NotProofOwner);
+            ensure!(status.is_active(), Error::<T>::ProofAlreadyRevoked);
+            ensure!(!extension.is_zero(), Error::<T>::InvalidLifetime);
+
+            // 只有未过期的限时存证可以续期
+            let expires_at =
+                Expiries::<T>::get(DEFAULT_NAMESPACE, &claim).ok_or(Error::<T>::ProofNotExpiring)?;
+            ensure!(
+                expires_at > frame_system::Pallet::<T>::block_number(),
+                Error::<T>::ProofExpired
+            );
+
+            let new_expires_at = expires_at.saturating_add(extension);
+            Self::cancel_expiry(DEFAULT_NAMESPACE, &claim);
+            Self::schedule_expiry(DEFAULT_NAMESPACE, &claim, new_expires_at);
+
+            Self::deposit_event(Event::ClaimRenewed { owner: who, claim, expires_at: new_expires_at });
+
+            Ok(Some(T::WeightInfo::renew_claim(len)).into())
+        }
+
+        /// Offer to transfer a claim to `to`. The transfer only happens once `to` accepts it
+        /// with `accept_claim` within `OfferTimeout` blocks. A new offer replaces the old one.
+        #[pallet::call_index(4)]
+        #[pallet::weight(T::WeightInfo::offer_claim(claim.len() as u32))]
+        pub fn offer_claim(
+            origin: OriginFor<T>,
+            claim: BoundedVec<u8, T::MaxClaimLength>,
+            to: T::AccountId,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            let len = claim.len() as u32;
+
+            let ClaimInfo { owner, status, .. } = Proofs::<T>::get(DEFAULT_NAMESPACE, &claim).ok_or(Error::<T>::ProofNotExist)?;
+            ensure!(owner == who, Error::<T>::NotProofOwner);
+            ensure!(status.is_active(), Error::<T>::ProofAlreadyRevoked);
+            ensure!(!Self::is_expired(DEFAULT_NAMESPACE, &claim), Error::<T>::ProofExpired);
+            ensure!(owner != to, Error::<T>::CannotTransferToSelf);
+            ensure!(!CoOwners::<T>::contains_key(DEFAULT_NAMESPACE, &claim), Error::<T>::RequiresApproval);
+            ensure!(!Challenges::<T>::contains_key(DEFAULT_NAMESPACE, &claim), Error::<T>::ClaimDisputed);
+            ensure!(!Self::is_locked(&status), Error::<T>::ClaimLocked);
+
+            let expires_at =
+                frame_system::Pallet::<T>::block_number().saturating_add(T::OfferTimeout::get());
+            Self::take_offer(DEFAULT_NAMESPACE, &claim);
+            Self::put_offer(DEFAULT_NAMESPACE, &claim, PendingTransfer { to: to.clone(), expires_at });
+
+            Self::deposit_event(Event::ClaimOffered { owner: who, to, claim, expires_at });
+
+            Ok(Some(T::WeightInfo::offer_claim(len)).into())
+        }
+
+        /// Accept a pending transfer offer made to the caller. An offer past its deadline is
+        /// removed instead and `OfferExpired` is emitted.
+        #[pallet::call_index(5)]
+        #[pallet::weight(T::WeightInfo::accept_claim(claim.len() as u32))]
+        pub fn accept_claim(
+            origin: OriginFor<T>,
+            claim: BoundedVec<u8, T::MaxClaimLength>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            let len = claim.len() as u32;
+
+            let offer = PendingTransfers::<T>::get(DEFAULT_NAMESPACE, &claim).ok_or(Error::<T>::OfferNotExist)?;
+            ensure!(offer.to == who, Error::<T>::NotOfferParty);
+            // 过期的要约直接移除；返回错误会回滚移除操作
+            if offer.expires_at <= frame_system::Pallet::<T>::block_number() {
+                Self::take_offer(DEFAULT_NAMESPACE, &claim);
+                Self::deposit_event(Event::OfferExpired { to: who, claim });
+                return Ok(Some(T::WeightInfo::accept_claim(len)).into());
+            }
+
+            let ClaimInfo { owner, status, .. } =
+                Proofs::<T>::get(DEFAULT_NAMESPACE, &claim).ok_or(Error::<T>::ProofNotExist)?;
+            ensure!(status.is_active(), Error::<T>::ProofAlreadyRevoked);
+            ensure!(!Self::is_expired(DEFAULT_NAMESPACE, &claim), Error::<T>::ProofExpired);
+            ensure!(!Challenges::<T>::contains_key(DEFAULT_NAMESPACE, &claim), Error::<T>::ClaimDisputed);
+            ensure!(!Self::is_locked(&status), Error::<T>::ClaimLocked);
+
+            Self::do_transfer(DEFAULT_NAMESPACE, claim.clone(), owner.clone(), who.clone(), false)?;
+
+            Self::deposit_event(Event::OfferAccepted { old_owner: owner, new_owner: who, claim });
+
+            Ok(Some(T::WeightInfo::accept_claim(len)).into())
+        }
+
+        /// Withdraw a pending transfer offer. Can be called by the owner or by the recipient, and
+        /// by anyone once the offer has passed its deadline.
+        #[pallet::call_index(6)]
+        #[pallet::weight(T::WeightInfo::cancel_offer(claim.len() as u32))]
+        pub fn cancel_offer(
+            origin: OriginFor<T>,
+            claim: BoundedVec<u8, T::MaxClaimLength>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            let len = claim.len() as u32;
+
+            let offer = PendingTransfers::<T>::get(DEFAULT_NAMESPACE, &claim).ok_or(Error::<T>::OfferNotExist)?;
+            if offer.expires_at <= frame_system::Pallet::<T>::block_number() {
+                Self::take_offer(DEFAULT_NAMESPACE, &claim);
+                Self::deposit_event(Event::OfferExpired { to: offer.to, claim });
+                return Ok(Some(T::WeightInfo::cancel_offer(len)).into());
+            }
+
+            let ClaimInfo { owner, .. } = Proofs::<T>::get(DEFAULT_NAMESPACE, &claim).ok_or(Error::<T>::ProofNotExist)?;
+            ensure!(who == owner || who == offer.to, Error::<T>::NotOfferParty);
+
+            Self::take_offer(DEFAULT_NAMESPACE, &claim);
+
+            Self::deposit_event(Event::OfferCancelled { to: offer.to, claim });
+
+            Ok(Some(T::WeightInfo::cancel_offer(len)).into())
+        }
+
+        /// Make a claim jointly owned. `co_owners` must include the owner, and from then on
+        /// `threshold` of them have to approve any revocation or transfer via `approve_action`.
+        #[pallet::call_index(7)]
+        #[pallet::weight(T::WeightInfo::set_co_owners(claim.len() as u32, co_owners.len() as u32))]
+        pub fn set_co_owners(
+            origin: OriginFor<T>,
+            claim: BoundedVec<u8, T::MaxClaimLength>,
+            co_owners: BoundedVec<T::AccountId, T::MaxCoOwners>,
+            threshold: u32,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            let len = claim.len() as u32;
+            let count = co_owners.len() as u32;
+
+            let ClaimInfo { owner, status, .. } = Proofs::<T>::get(DEFAULT_NAMESPACE, &claim).ok_or(Error::<T>::ProofNotExist)?;
+            ensure!(owner == who, Error::<T>::NotProofOwner);
+            ensure!(status.is_active(), Error::<T>::ProofAlreadyRevoked);
+            ensure!(!Self::is_expired(DEFAULT_NAMESPACE, &claim), Error::<T>::ProofExpired);
+            ensure!(!CoOwners::<T>::contains_key(DEFAULT_NAMESPACE, &claim), Error::<T>::AlreadyJointlyOwned);
+
+            // 共有人不能重复，且必须包含所有者
+            let mut sorted = co_owners.clone().into_inner();
+            sorted.sort();
+            sorted.dedup();
+            ensure!(
+                sorted.len() == co_owners.len() && co_owners.contains(&owner),
+                Error::<T>::InvalidCoOwners
+            );
+            ensure!(
+                threshold > 0 && threshold as usize <= co_owners.len(),
+                Error::<T>::InvalidThreshold
+            );
+
+            // 共有存证不能再通过单方要约转移
+            Self::take_offer(DEFAULT_NAMESPACE, &claim);
+            CoOwners::<T>::insert(
+                DEFAULT_NAMESPACE,
+                &claim,
+                JointOwnership { co_owners: co_owners.clone(), threshold },
+            );
+
+            Self::deposit_event(Event::CoOwnersSet { claim, co_owners, threshold });
+
+            Ok(Some(T::WeightInfo::set_co_owners(len, count)).into())
+        }
+
+        /// Approve revoking or transferring a jointly owned claim. The action is executed once
+        /// `threshold` co-owners have approved it. Several actions can collect approvals at the
+        /// same time, but each co-owner backs only one of them: approving another action
+        /// withdraws the caller's earlier approval.
+        #[pallet::call_index(8)]
+        #[pallet::weight(T::WeightInfo::approve_action(claim.len() as u32))]
+        pub fn approve_action(
+            origin: OriginFor<T>,
+            claim: BoundedVec<u8, T::MaxClaimLength>,
+            action: JointAction<T::AccountId, BlockNumberFor<T>>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            let len = claim.len() as u32;
+
+            let ClaimInfo { owner, status, .. } =
+                Proofs::<T>::get(DEFAULT_NAMESPACE, &claim).ok_or(Error::<T>::ProofNotExist)?;
+            ensure!(status.is_active(), Error::<T>::ProofAlreadyRevoked);
+            ensure!(!Self::is_expired(DEFAULT_NAMESPACE, &claim), Error::<T>::ProofExpired);
+            ensure!(!Challenges::<T>::contains_key(DEFAULT_NAMESPACE, &claim), Error::<T>::ClaimDisputed);
+            ensure!(!Self::is_locked(&status), Error::<T>::ClaimLocked);
+            let joint = CoOwners::<T>::get(DEFAULT_NAMESPACE, &claim).ok_or(Error::<T>::NotCoOwner)?;
+            ensure!(joint.co_owners.contains(&who), Error::<T>::NotCoOwner);
+            match action {
+                JointAction::Revoke(reason) =>
+                    ensure!(reason != RevocationReason::Expired, Error::<T>::InvalidRevocationReason),
+                JointAction::Transfer(ref new_owner) =>
+                    ensure!(*new_owner != owner, Error::<T>::CannotTransferToSelf),
+                JointAction::Lock { until, .. } => ensure!(
+                    until > frame_system::Pallet::<T>::block_number(),
+                    Error::<T>::InvalidLockPeriod
+                ),
+            }
+
+            // 每项操作单独收集批准，其他共有人无法通过批准别的操作清空已有的批准
+            let action_hash = T::Hashing::hash_of(&action);
+            let mut pending = Approvals::<T>::get((DEFAULT_NAMESPACE, &claim, action_hash))
+                .unwrap_or_else(|| PendingApproval { action: action.clone(), approvals: Default::default() });
+            ensure!(!pending.approvals.contains(&who), Error::<T>::AlreadyApproved);
+            Self::withdraw_approval(DEFAULT_NAMESPACE, &claim, &who);
+            // 批准人都是共有人，数量不会超过上限
+            pending.approvals.try_push(who.clone()).map_err(|_| Error::<T>::NotCoOwner)?;
+            let approvals = pending.approvals.len() as u32;
+
+            Self::deposit_event(Event::ActionApproved {
+                who: who.clone(),
+                claim: claim.clone(),
+                action: action.clone(),
+                approvals,
+            });
+
+            if approvals < joint.threshold {
+                Approvals::<T>::insert((DEFAULT_NAMESPACE, &claim, action_hash), pending);
+                return Ok(Some(T::WeightInfo::approve_action(len)).into());
+            }
+
+            match action.clone() {
+                JointAction::Revoke(reason) =>
+                    Self::do_revoke(DEFAULT_NAMESPACE, claim.clone(), owner, Some(who), reason)?,
+                JointAction::Transfer(new_owner) =>
+                    Self::do_transfer(DEFAULT_NAMESPACE, claim.clone(), owner, new_owner, false)?,
+                JointAction::Lock { until, beneficiary } => {
+                    Self::do_lock(DEFAULT_NAMESPACE, claim.clone(), owner, until, beneficiary);
+                    // 锁定期间无法执行其他操作，清除其余待批准的操作
+                    Self::clear_approvals(DEFAULT_NAMESPACE, &claim);
+                },
+            }
+
+            Self::deposit_event(Event::ActionExecuted { claim, action });
+
+            Ok(Some(T::WeightInfo::approve_action(len)).into())
+        }
+
+        /// Create a claim keyed by the hash of `content`, computed on-chain with `T::Hashing`,
+        /// and stored in [`HashedClaims`]. The same content always yields the same key, so it
+        /// cannot be registered twice. The deposit covers the hash, as for [`Roots`]. The content
+        /// itself is only kept off-chain, under [`offchain_content_key`], by nodes running with
+        /// offchain indexing enabled.
+        #[pallet::call_index(10)]
+        #[pallet::weight(T::WeightInfo::create_claim_from_content(content.len() as u32)
+            .saturating_add(T::WeightInfo::check_rate_limit()))]
+        pub fn create_claim_from_content(
+            origin: OriginFor<T>,
+            content: BoundedVec<u8, T::MaxContentLength>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            let len = content.len() as u32;
+
+            let hash = T::Hashing::hash(&content);
+            ensure!(!HashedClaims::<T>::contains_key(hash), Error::<T>::ProofAlreadyExist);
+
+            let deposit = Self::deposit_of_len(hash.as_ref().len() as u32);
+            T::Currency::hold(&HoldReason::ClaimDeposit.into(), &who, deposit)
+                .map_err(|_| Error::<T>::InsufficientDeposit)?;
+
+            let timestamp = Self::now();
+            HashedClaims::<T>::insert(
+                hash,
+                HashedClaim {
+                    owner: who.clone(),
+                    created_at: frame_system::Pallet::<T>::block_number(),
+                    created_time: timestamp,
+                    deposit,
+                },
+            );
+            // 原文写入链下索引，不占用链上存储
+            sp_io::offchain_index::set(&offchain_content_key(&hash), &content);
+
+            Self::deposit_event(Event::HashedClaimCreated { owner: who, hash, timestamp });
+
+            Ok(Some(
+                T::WeightInfo::create_claim_from_content(len).saturating_add(T::WeightInfo::check_rate_limit()),
+            )
+            .into())
+        }
+
+        /// Create several claims in one call. Every item is charged as if it succeeds; the
+        /// difference is refunded for the items that fail.
+        #[pallet::call_index(11)]
+        #[pallet::weight(T::WeightInfo::create_claims(claims.len() as u32, 0, Pallet::<T>::total_len(claims))
+            .saturating_add(T::WeightInfo::check_rate_limit()))]
+        pub fn create_claims(
+            origin: OriginFor<T>,
+            claims: BoundedVec<BoundedVec<u8, T::MaxClaimLength>, T::MaxBatchSize>,
+            mode: BatchMode,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            Self::run_batch(
+                claims,
+                mode,
+                |n, f, b| T::WeightInfo::create_claims(n, f, b).saturating_add(T::WeightInfo::check_rate_limit()),
+                |claim| Self::do_create(who.clone(), DEFAULT_NAMESPACE, claim, None, None),
+            )
+        }
+
+        /// Revoke several claims in one call. Every item is charged as if it succeeds; the
+        /// difference is refunded for the items that fail.
+        #[pallet::call_index(12)]
+        #[pallet::weight(T::WeightInfo::revoke_claims(claims.len() as u32, 0, Pallet::<T>::total_len(claims)))]
+        pub fn revoke_claims(
+            origin: OriginFor<T>,
+            claims: BoundedVec<BoundedVec<u8, T::MaxClaimLength>, T::MaxBatchSize>,
+            reason: RevocationReason,
+            mode: BatchMode,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            Self::run_batch(claims, mode, T::WeightInfo::revoke_claims, |claim| {
+                Self::try_revoke(who.clone(), DEFAULT_NAMESPACE, claim, reason)
+            })
+        }
+
+        /// Transfer several claims to `new_owner` in one call. Every item is charged as if it
+        /// succeeds; the difference is refunded for the items that fail.
+        #[pallet::call_index(13)]
+        #[pallet::weight(T::WeightInfo::transfer_claims(claims.len() as u32, 0, Pallet::<T>::total_len(claims)))]
+        pub fn transfer_claims(
+            origin: OriginFor<T>,
+            claims: BoundedVec<BoundedVec<u8, T::MaxClaimLength>, T::MaxBatchSize>,
+            new_owner: T::AccountId,
+            mode: BatchMode,
+        ) -> DispatchResultWithPostInfo {
+            let sender = ensure_signed(origin)?;
+            ensure!(T::AllowDirectTransfer::get(), Error::<T>::DirectTransferDisabled);
+            Self::run_batch(claims, mode, T::WeightInfo::transfer_claims, |claim| {
+                Self::try_transfer(sender.clone(), DEFAULT_NAMESPACE, claim, new_owner.clone())
+            })
+        }
+
+        /// Anchor a Merkle root built with `T::Hashing` over a set of document hashes. Inclusion
+        /// of a single document can later be checked with [`Pallet::verify_inclusion`].
+        #[pallet::call_index(14)]
+        #[pallet::weight(T::WeightInfo::anchor_root())]
+        pub fn anchor_root(origin: OriginFor<T>, root: T::Hash) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(!Roots::<T>::contains_key(root), Error::<T>::RootAlreadyAnchored);
+
+            // 与存证相同，按哈希长度锁定押金
+            let deposit = T::ClaimDeposit::get()
+                .saturating_add(T::DepositPerByte::get().saturating_mul((root.as_ref().len() as u32).into()));
+            T::Currency::hold(&HoldReason::ClaimDeposit.into(), &who, deposit)
+                .map_err(|_| Error::<T>::InsufficientDeposit)?;
+
+            Roots::<T>::insert(
+                root,
+                AnchoredRoot {
+                    owner: who.clone(),
+                    anchored_at: frame_system::Pallet::<T>::block_number(),
+                    deposit,
+                },
+            );
+
+            Self::deposit_event(Event::RootAnchored { owner: who, root });
+
+            Ok(())
+        }
+
+        /// Remove an anchored Merkle root and release its deposit.
+        #[pallet::call_index(15)]
+        #[pallet::weight(T::WeightInfo::remove_root())]
+        pub fn remove_root(origin: OriginFor<T>, root: T::Hash) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let anchored = Roots::<T>::get(root).ok_or(Error::<T>::RootNotAnchored)?;
+            ensure!(anchored.owner == who, Error::<T>::NotRootOwner);
+
+            Roots::<T>::remove(root);
+            T::Currency::release(
+                &HoldReason::ClaimDeposit.into(),
+                &who,
+                anchored.deposit,
+                Precision::BestEffort,
+            )?;
+
+            Self::deposit_event(Event::RootRemoved { owner: who, root });
+
+            Ok(())
+        }
+
+        /// Replace the metadata of a claim. The deposit is adjusted to the new metadata size.
+        #[pallet::call_index(9)]
+        #[pallet::weight(T::WeightInfo::set_claim_metadata(claim.len() as u32))]
+        pub fn set_claim_metadata(
+            origin: OriginFor<T>,
+            claim: BoundedVec<u8, T::MaxClaimLength>,
+            metadata: ClaimMetadata<T>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            let len = claim.len() as u32;
+
+            let ClaimInfo { owner, status, .. } = Proofs::<T>::get(DEFAULT_NAMESPACE, &claim).ok_or(Error::<T>::ProofNotExist)?;
+            ensure!(owner == who, Error::<T>::NotProofOwner);
+            ensure!(status.is_active(), Error::<T>::ProofAlreadyRevoked);
+            ensure!(!Self::is_expired(DEFAULT_NAMESPACE, &claim), Error::<T>::ProofExpired);
+
+            Metadata::<T>::insert(DEFAULT_NAMESPACE, &claim, &metadata);
+            Self::update_deposit(DEFAULT_NAMESPACE, &claim, &owner)?;
+            Proofs::<T>::mutate(DEFAULT_NAMESPACE, &claim, |info| {
+                if let Some(info) = info {
+                    info.updated_at = frame_system::Pallet::<T>::block_number();
+                    info.updated_time = Self::now();
+                }
+            });
+
+            Self::deposit_event(Event::ClaimMetadataUpdated { owner: who, claim, metadata });
+
+            Ok(Some(T::WeightInfo::set_claim_metadata(len)).into())
+        }
+
+        /// Allow `operator` to transfer or revoke `claim` on behalf of its owner, or clear the
+        /// approval with `None`. The approval is cleared whenever the claim changes hands. Can be
+        /// called by the owner or by one of its operators.
+        #[pallet::call_index(16)]
+        #[pallet::weight(T::WeightInfo::approve(claim.len() as u32))]
+        pub fn approve(
+            origin: OriginFor<T>,
+            claim: BoundedVec<u8, T::MaxClaimLength>,
+            operator: Option<T::AccountId>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            let len = claim.len() as u32;
+
+            let ClaimInfo { owner, status, .. } = Proofs::<T>::get(DEFAULT_NAMESPACE, &claim).ok_or(Error::<T>::ProofNotExist)?;
+            ensure!(
+                who == owner || Operators::<T>::contains_key(&owner, &who),
+                Error::<T>::NotProofOwner
+            );
+            ensure!(status.is_active(), Error::<T>::ProofAlreadyRevoked);
+            ensure!(operator.as_ref() != Some(&owner), Error::<T>::OperatorIsOwner);
+
+            match operator {
+                Some(ref operator) => ClaimOperators::<T>::insert(DEFAULT_NAMESPACE, &claim, operator),
+                None => ClaimOperators::<T>::remove(DEFAULT_NAMESPACE, &claim),
+            }
+
+            Self::deposit_event(Event::OperatorApproved { owner, claim, operator });
+
+            Ok(Some(T::WeightInfo::approve(len)).into())
+        }
+
+        /// Allow or disallow `operator` to manage every claim of the caller.
+        #[pallet::call_index(17)]
+        #[pallet::weight(T::WeightInfo::set_operator())]
+        pub fn set_operator(
+            origin: OriginFor<T>,
+            operator: T::AccountId,
+            approved: bool,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(operator != who, Error::<T>::OperatorIsOwner);
+
+            if approved {
+                Operators::<T>::insert(&who, &operator, ());
+            } else {
+                Operators::<T>::remove(&who, &operator);
+            }
+
+            Self::deposit_event(Event::OperatorSet { owner: who, operator, approved });
+
+            Ok(())
+        }
+
+        /// Transfer `claim` from `from` to `new_owner`. The caller must be `from` or an operator
+        /// approved by `from`; the call fails if `from` no longer owns the claim.
+        #[pallet::call_index(18)]
+        #[pallet::weight(T::WeightInfo::transfer_claim_from(claim.len() as u32))]
+        pub fn transfer_claim_from(
+            origin: OriginFor<T>,
+            claim: BoundedVec<u8, T::MaxClaimLength>,
+            from: T::AccountId,
+            new_owner: T::AccountId,
+        ) -> DispatchResultWithPostInfo {
+            let sender = ensure_signed(origin)?;
+            let len = claim.len() as u32;
+            ensure!(T::AllowDirectTransfer::get(), Error::<T>::DirectTransferDisabled);
+
+            let ClaimInfo { owner, .. } = Proofs::<T>::get(DEFAULT_NAMESPACE, &claim).ok_or(Error::<T>::ProofNotExist)?;
+            ensure!(owner == from, Error::<T>::OwnerMismatch);
+
+            Self::try_transfer(sender, DEFAULT_NAMESPACE, claim, new_owner)?;
+
+            Ok(Some(T::WeightInfo::transfer_claim_from(len)).into())
+        }
+
+        /// Create a claim owned by `owner`, ignoring the re-registration policy. The deposit is
+        /// held from `owner`. Only callable by `ForceOrigin`.
+        #[pallet::call_index(19)]
+        #[pallet::weight(T::WeightInfo::force_create_claim(claim.len() as u32))]
+        pub fn force_create_claim(
+            origin: OriginFor<T>,
+            claim: BoundedVec<u8, T::MaxClaimLength>,
+            owner: T::AccountId,
+        ) -> DispatchResultWithPostInfo {
+            T::ForceOrigin::ensure_origin(origin)?;
+            let len = claim.len() as u32;
+
+            Self::insert_claim(owner.clone(), DEFAULT_NAMESPACE, claim.clone(), None, None, Self::now())?;
+
+            // 强制操作只发出一个事件，便于审计
+            Self::deposit_event(Event::ClaimForceCreated { owner, claim });
+
+            Ok(Some(T::WeightInfo::force_create_claim(len)).into())
+        }
+
+        /// Transfer a claim to `new_owner` without the consent of its owner, dissolving any joint
+        /// ownership and returning the bond of a pending challenge. Only callable by
+        /// `ForceOrigin`.
+        #[pallet::call_index(20)]
+        #[pallet::weight(T::WeightInfo::force_transfer_claim(claim.len() as u32))]
+        pub fn force_transfer_claim(
+            origin: OriginFor<T>,
+            claim: BoundedVec<u8, T::MaxClaimLength>,
+            new_owner: T::AccountId,
+        ) -> DispatchResultWithPostInfo {
+            T::ForceOrigin::ensure_origin(origin)?;
+            let len = claim.len() as u32;
+
+            let ClaimInfo { owner, status, .. } =
+                Proofs::<T>::get(DEFAULT_NAMESPACE, &claim).ok_or(Error::<T>::ProofNotExist)?;
+            ensure!(status.is_active(), Error::<T>::ProofAlreadyRevoked);
+            ensure!(!Self::is_expired(DEFAULT_NAMESPACE, &claim), Error::<T>::ProofExpired);
+            ensure!(owner != new_owner, Error::<T>::CannotTransferToSelf);
+
+            Self::do_transfer(DEFAULT_NAMESPACE, claim, owner, new_owner, true)?;
+
+            Ok(Some(T::WeightInfo::force_transfer_claim(len)).into())
+        }
+
+        /// Revoke a claim without the consent of its owner. The tombstone records no revoker.
+        /// Only callable by `ForceOrigin`.
+        #[pallet::call_index(21)]
+        #[pallet::weight(T::WeightInfo::force_revoke_claim(claim.len() as u32))]
+        pub fn force_revoke_claim(
+            origin: OriginFor<T>,
+            claim: BoundedVec<u8, T::MaxClaimLength>,
+            reason: RevocationReason,
+        ) -> DispatchResultWithPostInfo {
+            T::ForceOrigin::ensure_origin(origin)?;
+            let len = claim.len() as u32;
+            ensure!(reason != RevocationReason::Expired, Error::<T>::InvalidRevocationReason);
+
+            let ClaimInfo { owner, status, .. } = Proofs::<T>::get(DEFAULT_NAMESPACE, &claim).ok_or(Error::<T>::ProofNotExist)?;
+            ensure!(status.is_active(), Error::<T>::ProofAlreadyRevoked);
+
+            Self::do_revoke(DEFAULT_NAMESPACE, claim, owner, None, reason)?;
+
+            Ok(Some(T::WeightInfo::force_revoke_claim(len)).into())
+        }
+
+        /// Dispute the ownership of a claim. `ChallengeBond` is held from the caller and the
+        /// claim cannot be transferred or revoked until `ArbiterOrigin` resolves the challenge
+        /// or it expires after `ChallengePeriod` blocks. The caller also pays for returning the
+        /// bond in `on_initialize` when the challenge expires.
+        #[pallet::call_index(22)]
+        #[pallet::weight(T::WeightInfo::challenge_claim(claim.len() as u32)
+            .saturating_add(T::WeightInfo::expire_challenge()))]
+        pub fn challenge_claim(
+            origin: OriginFor<T>,
+            claim: BoundedVec<u8, T::MaxClaimLength>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            let len = claim.len() as u32;
+
+            let ClaimInfo { owner, status, .. } = Proofs::<T>::get(DEFAULT_NAMESPACE, &claim).ok_or(Error::<T>::ProofNotExist)?;
+            ensure!(status.is_active(), Error::<T>::ProofAlreadyRevoked);
+            ensure!(!Self::is_expired(DEFAULT_NAMESPACE, &claim), Error::<T>::ProofExpired);
+            ensure!(owner != who, Error::<T>::CannotChallengeOwnClaim);
+            ensure!(!Challenges::<T>::contains_key(DEFAULT_NAMESPACE, &claim), Error::<T>::AlreadyChallenged);
+
+            // 锁定质疑保证金
+            let bond = T::ChallengeBond::get();
+            T::Currency::hold(&HoldReason::ChallengeBond.into(), &who, bond)
+                .map_err(|_| Error::<T>::InsufficientBond)?;
+
+            let expires_at =
+                frame_system::Pallet::<T>::block_number().saturating_add(T::ChallengePeriod::get());
+            Challenges::<T>::insert(DEFAULT_NAMESPACE, &claim, Challenge { challenger: who.clone(), bond, expires_at });
+            ChallengeQueue::<T>::insert(expires_at, (DEFAULT_NAMESPACE, &claim), ());
+
+            Self::deposit_event(Event::ClaimChallenged { challenger: who, claim, expires_at });
+
+            Ok(Some(
+                T::WeightInfo::challenge_claim(len).saturating_add(T::WeightInfo::expire_challenge()),
+            )
+            .into())
+        }
+
+        /// Resolve the challenge of a claim. A rejected challenge burns the challenger's bond;
+        /// an upheld one returns it and may reassign the claim, with the new owner paying the
+        /// claim deposit. Only callable by `ArbiterOrigin`.
+        #[pallet::call_index(23)]
+        #[pallet::weight(T::WeightInfo::resolve_challenge(claim.len() as u32))]
+        pub fn resolve_challenge(
+            origin: OriginFor<T>,
+            claim: BoundedVec<u8, T::MaxClaimLength>,
+            verdict: Verdict<T::AccountId>,
+        ) -> DispatchResultWithPostInfo {
+            T::ArbiterOrigin::ensure_origin(origin)?;
+            let len = claim.len() as u32;
+
+            let challenge = Self::take_challenge(DEFAULT_NAMESPACE, &claim).ok_or(Error::<T>::NotChallenged)?;
+            let reason: T::RuntimeHoldReason = HoldReason::ChallengeBond.into();
+            match verdict {
+                Verdict::Rejected => {
+                    // 质疑不成立，罚没保证金
+                    T::Currency::burn_held(
+                        &reason,
+                        &challenge.challenger,
+                        challenge.bond,
+                        Precision::BestEffort,
+                        Fortitude::Force,
+                    )?;
+                },
+                Verdict::Upheld { ref reassign_to } => {
+                    T::Currency::release(
+                        &reason,
+                        &challenge.challenger,
+                        challenge.bond,
+                        Precision::BestEffort,
+                    )?;
+                    if let Some(new_owner) = reassign_to {
+                        let ClaimInfo { owner, status, .. } =
+                            Proofs::<T>::get(DEFAULT_NAMESPACE, &claim).ok_or(Error::<T>::ProofNotExist)?;
+                        ensure!(status.is_active(), Error::<T>::ProofAlreadyRevoked);
+                        if owner != *new_owner {
+                            Self::do_transfer(DEFAULT_NAMESPACE, claim.clone(), owner, new_owner.clone(), false)?;
+                        }
+                    }
+                },
+            }
+
+            Self::deposit_event(Event::ChallengeResolved {
+                challenger: challenge.challenger,
+                claim,
+                verdict,
+            });
+
+            Ok(Some(T::WeightInfo::resolve_challenge(len)).into())
+        }
+
+        /// Create a claim owned by `owner` on their behalf. `owner` signs
+        /// [`Pallet::signed_claim_payload`] off-chain with their current nonce; the caller only
+        /// pays the fees, while the deposit is held from `owner`.
+        #[pallet::call_index(24)]
+        #[pallet::weight(T::WeightInfo::create_claim_signed(claim.len() as u32)
+            .saturating_add(T::WeightInfo::check_rate_limit()))]
+        pub fn create_claim_signed(
+            origin: OriginFor<T>,
+            claim: BoundedVec<u8, T::MaxClaimLength>,
+            owner: T::AccountId,
+            signature: T::OffchainSignature,
+            deadline: BlockNumberFor<T>,
+        ) -> DispatchResultWithPostInfo {
+            ensure_signed(origin)?;
+            let len = claim.len() as u32;
+            ensure!(
+                frame_system::Pallet::<T>::block_number() <= deadline,
+                Error::<T>::SignatureExpired
+            );
+
+            // 校验签名后递增 nonce，同一签名无法重放
+            let nonce = SignedClaimNonces::<T>::get(&owner);
+            let payload = Self::signed_claim_payload(&claim, &owner, nonce, deadline);
+            ensure!(signature.verify(&payload[..], &owner), Error::<T>::InvalidSignature);
+            SignedClaimNonces::<T>::insert(&owner, nonce.saturating_add(1));
+
+            Self::do_create(owner, DEFAULT_NAMESPACE, claim, None, None)?;
+
+            Ok(Some(
+                T::WeightInfo::create_claim_signed(len).saturating_add(T::WeightInfo::check_rate_limit()),
+            )
+            .into())
+        }
+
+        /// Create a namespace managed by `admin`. Only callable by `ForceOrigin`.
+        #[pallet::call_index(25)]
+        #[pallet::weight(T::WeightInfo::create_namespace())]
+        pub fn create_namespace(
+            origin: OriginFor<T>,
+            admin: T::AccountId,
+            policy: NamespacePolicy,
+        ) -> DispatchResult {
+            T::ForceOrigin::ensure_origin(origin)?;
+
+            let namespace = NextNamespaceId::<T>::get();
+            NextNamespaceId::<T>::put(namespace.saturating_add(1));
+            Namespaces::<T>::insert(namespace, Namespace { admin: admin.clone(), policy });
+
+            Self::deposit_event(Event::NamespaceCreated { namespace, admin, policy });
+
+            Ok(())
+        }
+
+        /// Hand a namespace over to `admin`. Callable by the current admin or `ForceOrigin`.
+        #[pallet::call_index(26)]
+        #[pallet::weight(T::WeightInfo::set_namespace_admin())]
+        pub fn set_namespace_admin(
+            origin: OriginFor<T>,
+            namespace: NamespaceId,
+            admin: T::AccountId,
+        ) -> DispatchResult {
+            Self::ensure_namespace_admin(origin, namespace)?;
+
+            Namespaces::<T>::mutate(namespace, |details| {
+                if let Some(details) = details {
+                    details.admin = admin.clone();
+                }
+            });
+
+            Self::deposit_event(Event::NamespaceAdminChanged { namespace, admin });
+
+            Ok(())
+        }
+
+        /// Change who may create claims in a namespace. Callable by the admin or `ForceOrigin`.
+        #[pallet::call_index(27)]
+        #[pallet::weight(T::WeightInfo::set_namespace_policy())]
+        pub fn set_namespace_policy(
+            origin: OriginFor<T>,
+            namespace: NamespaceId,
+            policy: NamespacePolicy,
+        ) -> DispatchResult {
+            Self::ensure_namespace_admin(origin, namespace)?;
+
+            Namespaces::<T>::mutate(namespace, |details| {
+                if let Some(details) = details {
+                    details.policy = policy;
+                }
+            });
+
+            Self::deposit_event(Event::NamespacePolicyChanged { namespace, policy });
+
+            Ok(())
+        }
+
+        /// Add `who` to or remove them from the allow-list of a namespace. Callable by the admin
+        /// or `ForceOrigin`.
+        #[pallet::call_index(28)]
+        #[pallet::weight(T::WeightInfo::set_namespace_member())]
+        pub fn set_namespace_member(
+            origin: OriginFor<T>,
+            namespace: NamespaceId,
+            who: T::AccountId,
+            allowed: bool,
+        ) -> DispatchResult {
+            Self::ensure_namespace_admin(origin, namespace)?;
+
+            if allowed {
+                NamespaceMembers::<T>::insert(namespace, &who, ());
+            } else {
+                NamespaceMembers::<T>::remove(namespace, &who);
+            }
+
+            Self::deposit_event(Event::NamespaceMemberSet { namespace, who, allowed });
+
+            Ok(())
+        }
+
+        /// Create a claim in `namespace`, as its policy allows. The claim counts towards
+        /// `MaxClaimsPerOwner` and is recorded, revoked and transferred like a claim in
+        /// [`DEFAULT_NAMESPACE`]. Lifetimes and metadata are only available there.
+        #[pallet::call_index(29)]
+        #[pallet::weight(T::WeightInfo::create_claim_in(claim.len() as u32)
+            .saturating_add(T::WeightInfo::check_rate_limit()))]
+        pub fn create_claim_in(
+            origin: OriginFor<T>,
+            namespace: NamespaceId,
+            claim: BoundedVec<u8, T::MaxClaimLength>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            let len = claim.len() as u32;
+            let details = Namespaces::<T>::get(namespace).ok_or(Error::<T>::NamespaceNotFound)?;
+            ensure!(
+                details.allows(&who, || NamespaceMembers::<T>::contains_key(namespace, &who)),
+                Error::<T>::NotAllowedInNamespace
+            );
+
+            Self::do_create(who, namespace, claim, None, None)?;
+
+            Ok(Some(
+                T::WeightInfo::create_claim_in(len).saturating_add(T::WeightInfo::check_rate_limit()),
+            )
+            .into())
+        }
+
+        /// Revoke a claim in `namespace`, leaving a tombstone. Callable by the owner, its
+        /// operators or the namespace admin.
+        #[pallet::call_index(30)]
+        #[pallet::weight(T::WeightInfo::revoke_claim_in(claim.len() as u32))]
+        pub fn revoke_claim_in(
+            origin: OriginFor<T>,
+            namespace: NamespaceId,
+            claim: BoundedVec<u8, T::MaxClaimLength>,
+            reason: RevocationReason,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            let len = claim.len() as u32;
+            ensure!(Namespaces::<T>::contains_key(namespace), Error::<T>::NamespaceNotFound);
+
+            Self::try_revoke(who, namespace, claim, reason)?;
+
+            Ok(Some(T::WeightInfo::revoke_claim_in(len)).into())
+        }
+
+        /// Transfer a claim in `namespace` to `new_owner`, who takes over the deposit. Callable
+        /// by the owner or its operators.
+        #[pallet::call_index(31)]
+        #[pallet::weight(T::WeightInfo::transfer_claim_in(claim.len() as u32))]
+        pub fn transfer_claim_in(
+            origin: OriginFor<T>,
+            namespace: NamespaceId,
+            claim: BoundedVec<u8, T::MaxClaimLength>,
+            new_owner: T::AccountId,
+        ) -> DispatchResultWithPostInfo {
+            let sender = ensure_signed(origin)?;
+            let len = claim.len() as u32;
+            ensure!(T::AllowDirectTransfer::get(), Error::<T>::DirectTransferDisabled);
+            ensure!(Namespaces::<T>::contains_key(namespace), Error::<T>::NamespaceNotFound);
+
+            Self::try_transfer(sender, namespace, claim, new_owner)?;
+
+            Ok(Some(T::WeightInfo::transfer_claim_in(len)).into())
+        }
+
+        /// Lock `claim` against transfer and revocation until block `until`, e.g. while it serves
+        /// as collateral. `beneficiary`, if given, may release the lock early. Only the owner can
+        /// lock a claim, and a lock in force cannot be replaced. Jointly owned claims are locked
+        /// through `approve_action` instead.
+        #[pallet::call_index(32)]
+        #[pallet::weight(T::WeightInfo::lock_claim(claim.len() as u32))]
+        pub fn lock_claim(
+            origin: OriginFor<T>,
+            claim: BoundedVec<u8, T::MaxClaimLength>,
+            until: BlockNumberFor<T>,
+            beneficiary: Option<T::AccountId>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            let len = claim.len() as u32;
+
+            let ClaimInfo { owner, status, .. } =
+                Proofs::<T>::get(DEFAULT_NAMESPACE, &claim).ok_or(Error::<T>::ProofNotExist)?;
+            ensure!(owner == who, Error::<T>::NotProofOwner);
+            ensure!(status.is_active(), Error::<T>::ProofAlreadyRevoked);
+            ensure!(!Self::is_expired(DEFAULT_NAMESPACE, &claim), Error::<T>::ProofExpired);
+            ensure!(!Self::is_locked(&status), Error::<T>::ClaimLocked);
+            ensure!(until > frame_system::Pallet::<T>::block_number(), Error::<T>::InvalidLockPeriod);
+            // 共有存证需要通过 approve_action 锁定
+            ensure!(!CoOwners::<T>::contains_key(DEFAULT_NAMESPACE, &claim), Error::<T>::RequiresApproval);
+
+            Self::do_lock(DEFAULT_NAMESPACE, claim, owner, until, beneficiary);
+
+            Ok(Some(T::WeightInfo::lock_claim(len)).into())
+        }
+
+        /// Release the lock on `claim`. The beneficiary may do so at any time, the owner only
+        /// once the lock has run out.
+        #[pallet::call_index(33)]
+        #[pallet::weight(T::WeightInfo::unlock_claim(claim.len() as u32))]
+        pub fn unlock_claim(
+            origin: OriginFor<T>,
+            claim: BoundedVec<u8, T::MaxClaimLength>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            let len = claim.len() as u32;
+
+            let ClaimInfo { owner, status, .. } =
+                Proofs::<T>::get(DEFAULT_NAMESPACE, &claim).ok_or(Error::<T>::ProofNotExist)?;
+            let ClaimStatus::Locked { ref beneficiary, .. } = status else {
+                return Err(Error::<T>::ClaimNotLocked.into());
+            };
+            if beneficiary.as_ref() != Some(&who) {
+                ensure!(owner == who, Error::<T>::NotProofOwner);
+                ensure!(!Self::is_locked(&status), Error::<T>::ClaimLocked);
+            }
+
+            Self::set_status(DEFAULT_NAMESPACE, &claim, ClaimStatus::Active);
+
+            Self::deposit_event(Event::ClaimUnlocked { claim, by: who });
+
+            Ok(Some(T::WeightInfo::unlock_claim(len)).into())
+        }
+
+        /// Revoke a claim created with `create_claim_from_content` and release its deposit.
+        /// The content hash can then be claimed again.
+        #[pallet::call_index(34)]
+        #[pallet::weight(T::WeightInfo::revoke_hashed_claim())]
+        pub fn revoke_hashed_claim(origin: OriginFor<T>, hash: T::Hash) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let claim = HashedClaims::<T>::get(hash).ok_or(Error::<T>::ProofNotExist)?;
+            ensure!(claim.owner == who, Error::<T>::NotProofOwner);
+
+            HashedClaims::<T>::remove(hash);
+            T::Currency::release(
+                &HoldReason::ClaimDeposit.into(),
+                &who,
+                claim.deposit,
+                Precision::BestEffort,
+            )?;
+
+            Self::deposit_event(Event::HashedClaimRevoked { owner: who, hash });
+
+            Ok(())
+        }
+
+        /// Transfer a claim created with `create_claim_from_content` to `new_owner`, who takes
+        /// over the deposit.
+        #[pallet::call_index(35)]
+        #[pallet::weight(T::WeightInfo::transfer_hashed_claim())]
+        pub fn transfer_hashed_claim(
+            origin: OriginFor<T>,
+            hash: T::Hash,
+            new_owner: T::AccountId,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(T::AllowDirectTransfer::get(), Error::<T>::DirectTransferDisabled);
+            let mut claim = HashedClaims::<T>::get(hash).ok_or(Error::<T>::ProofNotExist)?;
+            ensure!(claim.owner == who, Error::<T>::NotProofOwner);
+            ensure!(new_owner != who, Error::<T>::CannotTransferToSelf);
+
+            // 押金由新所有者重新锁定，再退还给原所有者
+            let deposit = Self::deposit_of_len(hash.as_ref().len() as u32);
+            T::Currency::hold(&HoldReason::ClaimDeposit.into(), &new_owner, deposit)
+                .map_err(|_| Error::<T>::InsufficientDeposit)?;
+            T::Currency::release(
+                &HoldReason::ClaimDeposit.into(),
+                &who,
+                claim.deposit,
+                Precision::BestEffort,
+            )?;
+
+            claim.owner = new_owner.clone();
+            claim.deposit = deposit;
+            HashedClaims::<T>::insert(hash, claim);
+
+            Self::deposit_event(Event::HashedClaimTransferred {
+                old_owner: who,
+                new_owner,
+                hash,
+                timestamp: Self::now(),
+            });
+
+            Ok(())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// The bytes `owner` signs to authorise `create_claim_signed`: the SCALE encoding of
+        /// [`SIGNED_CLAIM_DOMAIN`], the genesis hash, the claim, the owner, the nonce and the
+        /// deadline.
+        pub fn signed_claim_payload(
+            claim: &BoundedVec<u8, T::MaxClaimLength>,
+            owner: &T::AccountId,
+            nonce: u64,
+            deadline: BlockNumberFor<T>,
+        ) -> Vec<u8> {
+            let genesis_hash = frame_system::Pallet::<T>::block_hash(BlockNumberFor::<T>::zero());
+            (SIGNED_CLAIM_DOMAIN, genesis_hash, claim, owner, nonce, deadline).encode()
+        }
+
+        /// The deposit held for `claim` in `namespace`: the base deposit plus a per-byte amount
+        /// covering the claim and its metadata.
+        pub fn deposit_for(namespace: NamespaceId, claim: &BoundedVec<u8, T::MaxClaimLength>) -> BalanceOf<T> {
+            let metadata_len =
+                Metadata::<T>::get(namespace, claim).map_or(0, |metadata| metadata.byte_len());
+            Self::deposit_of_len((claim.len() as u32).saturating_add(metadata_len))
+        }
+
+        /// The base deposit plus the per-byte amount for `bytes` bytes.
+        fn deposit_of_len(bytes: u32) -> BalanceOf<T> {
+            T::ClaimDeposit::get().saturating_add(T::DepositPerByte::get().saturating_mul(bytes.into()))
+        }
+
+        /// Ensure that `origin` is `ForceOrigin` or the admin of `namespace`.
+        fn ensure_namespace_admin(origin: OriginFor<T>, namespace: NamespaceId) -> DispatchResult {
+            let details = Namespaces::<T>::get(namespace).ok_or(Error::<T>::NamespaceNotFound)?;
+            if let Err(origin) = T::ForceOrigin::try_origin(origin) {
+                let who = ensure_signed(origin)?;
+                ensure!(who == details.admin, Error::<T>::NotNamespaceAdmin);
+            }
+            Ok(())
+        }
+
+        /// Hold or release the difference between the recorded deposit of `claim` and what it
+        /// should be now.
+        fn update_deposit(
+            namespace: NamespaceId,
+            claim: &BoundedVec<u8, T::MaxClaimLength>,
+            owner: &T::AccountId,
+        ) -> DispatchResult {
+            let old = Deposits::<T>::get(namespace, claim).unwrap_or_default();
+            let new = Self::deposit_for(namespace, claim);
+            if new > old {
+                T::Currency::hold(&HoldReason::ClaimDeposit.into(), owner, new.saturating_sub(old))
+                    .map_err(|_| Error::<T>::InsufficientDeposit)?;
+            } else if old > new {
+                T::Currency::release(
+                    &HoldReason::ClaimDeposit.into(),
+                    owner,
+                    old.saturating_sub(new),
+                    Precision::BestEffort,
+                )?;
+            }
+            Deposits::<T>::insert(namespace, claim, new);
+            Ok(())
+        }
+
+        /// The details of `claim` in `namespace`, or `None` if it does not exist.
+        pub fn claim_details(
+            namespace: NamespaceId,
+            claim: Vec<u8>,
+        ) -> Option<ClaimDetails<T::AccountId, BlockNumberFor<T>>> {
+            let claim = BoundedVec::<u8, T::MaxClaimLength>::try_from(claim).ok()?;
+            let info = Proofs::<T>::get(namespace, &claim)?;
+            Some(ClaimDetails {
+                owner: info.owner,
+                created_at: info.created_at,
+                updated_at: info.updated_at,
+                created_time: info.created_time,
+                updated_time: info.updated_time,
+                is_active: info.status.is_active() && !Self::is_expired(namespace, &claim),
+                expires_at: Expiries::<T>::get(namespace, &claim),
+                locked_until: match info.status {
+                    ClaimStatus::Locked { until, .. } if Self::is_locked(&info.status) => Some(until),
+                    _ => None,
+                },
+            })
+        }
+
+        /// Up to `limit` active claims of `who` in any namespace, as `(namespace, claim)`, in
+        /// storage order, starting after `cursor`.
+        pub fn claims_of(
+            who: T::AccountId,
+            cursor: Option<(NamespaceId, Vec<u8>)>,
+            limit: u32,
+        ) -> Vec<(NamespaceId, Vec<u8>)> {
+            let limit = limit.min(T::MaxClaimsPerOwner::get()) as usize;
+            let claims = match cursor {
+                Some((namespace, cursor)) => {
+                    let Ok(cursor) = BoundedVec::<u8, T::MaxClaimLength>::try_from(cursor) else {
+                        return Vec::new();
+                    };
+                    let start = ClaimsByOwner::<T>::hashed_key_for(&who, (namespace, cursor));
+                    ClaimsByOwner::<T>::iter_key_prefix_from(&who, start)
+                },
+                None => ClaimsByOwner::<T>::iter_key_prefix(&who),
+            };
+            claims.take(limit).map(|(namespace, claim)| (namespace, claim.into_inner())).collect()
+        }
+
+        /// The provenance history of `claim` in `namespace`, oldest first.
+        pub fn claim_history(
+            namespace: NamespaceId,
+            claim: Vec<u8>,
+        ) -> Vec<ProvenanceRecord<T::AccountId, BlockNumberFor<T>>> {
+            BoundedVec::<u8, T::MaxClaimLength>::try_from(claim)
+                .map(|claim| ClaimHistory::<T>::get(namespace, &claim).into_inner())
+                .unwrap_or_default()
+        }
+
+        /// Whether the document hashed to `leaf` is included in the set committed to by the
+        /// anchored `root`.
+        ///
+        /// `proof` lists the sibling hashes from the leaf node up to the root. Leaves are hashed
+        /// with [`MERKLE_LEAF_PREFIX`] and inner nodes with [`MERKLE_NODE_PREFIX`], so an inner
+        /// node cannot be passed off as a leaf. Each pair of nodes is hashed in sorted order with
+        /// `T::Hashing`, so the proof needs no position bits.
+        pub fn verify_inclusion(leaf: T::Hash, proof: &[T::Hash], root: T::Hash) -> bool {
+            Roots::<T>::contains_key(root) && Self::merkle_root(leaf, proof) == root
+        }
+
+        /// The leaf node of the document hashed to `leaf`.
+        pub fn merkle_leaf(leaf: T::Hash) -> T::Hash {
+            let mut data = Vec::with_capacity(1 + leaf.as_ref().len());
+            data.push(MERKLE_LEAF_PREFIX);
+            data.extend_from_slice(leaf.as_ref());
+            T::Hashing::hash(&data)
+        }
+
+        /// The parent of the nodes `a` and `b`, which are hashed in sorted order.
+        pub fn merkle_node(a: T::Hash, b: T::Hash) -> T::Hash {
+            let (left, right) = if a <= b { (a, b) } else { (b, a) };
+            let mut data = Vec::with_capacity(1 + left.as_ref().len() + right.as_ref().len());
+            data.push(MERKLE_NODE_PREFIX);
+            data.extend_from_slice(left.as_ref());
+            data.extend_from_slice(right.as_ref());
+            T::Hashing::hash(&data)
+        }
+
+        /// The root obtained by folding `proof` into the leaf node of `leaf`.
+        pub fn merkle_root(leaf: T::Hash, proof: &[T::Hash]) -> T::Hash {
+            proof.iter().fold(Self::merkle_leaf(leaf), |node, sibling| Self::merkle_node(node, *sibling))
+        }
+
+        /// Replace the status of `claim`, recording the change as an update.
+        fn set_status(
+            namespace: NamespaceId,
+            claim: &BoundedVec<u8, T::MaxClaimLength>,
+            status: ClaimStatus<T::AccountId, BlockNumberFor<T>>,
+        ) {
+            Proofs::<T>::mutate(namespace, claim, |info| {
+                if let Some(info) = info {
+                    info.status = status;
+                    info.updated_at = frame_system::Pallet::<T>::block_number();
+                    info.updated_time = Self::now();
+                }
+            });
+        }
+
+        /// The number of claims `call` creates, as counted by [`CheckClaimRateLimit`].
+        pub fn claims_created_by(call: &Call<T>) -> u32 {
+            match call {
+                Call::create_claim { .. } |
+                Call::create_claim_from_content { .. } |
+                Call::create_claim_signed { .. } |
+                Call::create_claim_in { .. } => 1,
+                Call::create_claims { claims, .. } => claims.len() as u32,
+                _ => 0,
+            }
+        }
+
+        /// The rate-limit window of `who` after creating `claims` more claims in the current
+        /// block, or `None` if that would exceed `MaxClaimsPerAccountPerWindow`. A window that
+        /// has run out is replaced by one starting now.
+        pub(crate) fn rate_window_after(
+            who: &T::AccountId,
+            claims: u32,
+        ) -> Option<(BlockNumberFor<T>, u32)> {
+            let now = frame_system::Pallet::<T>::block_number();
+            let (start, used) = match ClaimRateWindows::<T>::get(who) {
+                Some((start, used)) if now < start.saturating_add(T::WindowLength::get()) => (start, used),
+                _ => (now, 0),
+            };
+            let used = used.saturating_add(claims);
+            (used <= T::MaxClaimsPerAccountPerWindow::get()).then_some((start, used))
+        }
+
+        /// Store the rate-limit window of `who`, moving it in [`RateWindowQueue`] when a new
+        /// window has started.
+        pub(crate) fn put_rate_window(who: &T::AccountId, window: (BlockNumberFor<T>, u32)) {
+            let length = T::WindowLength::get();
+            let previous = ClaimRateWindows::<T>::get(who).map(|(start, _)| start);
+            if previous != Some(window.0) {
+                if let Some(start) = previous {
+                    RateWindowQueue::<T>::remove(start.saturating_add(length), who);
+                }
+                let ends_at = window.0.saturating_add(length);
+                RateWindowQueue::<T>::insert(ends_at, who, ());
+                NextRateWindowCheck::<T>::mutate(|cursor| {
+                    *cursor = Some(cursor.map_or(ends_at, |cursor| cursor.min(ends_at)))
+                });
+            }
+            ClaimRateWindows::<T>::insert(who, window);
+        }
+
+        /// Whether a claim with `status` is locked in the current block.
+        fn is_locked(status: &ClaimStatus<T::AccountId, BlockNumberFor<T>>) -> bool {
+            status.is_locked(&frame_system::Pallet::<T>::block_number())
+        }
+
+        /// Whether `claim` in `namespace` has reached its expiry block.
+        pub fn is_expired(namespace: NamespaceId, claim: &BoundedVec<u8, T::MaxClaimLength>) -> bool {
+            Expiries::<T>::get(namespace, claim)
+                .map_or(false, |expires_at| expires_at <= frame_system::Pallet::<T>::block_number())
+        }
+
+        /// Whether `who` is the owner of `claim` or an operator approved by `owner`.
+        pub fn is_owner_or_operator(
+            who: &T::AccountId,
+            owner: &T::AccountId,
+            namespace: NamespaceId,
+            claim: &BoundedVec<u8, T::MaxClaimLength>,
+        ) -> bool {
+            who == owner ||
+                ClaimOperators::<T>::get(namespace, claim).as_ref() == Some(who) ||
+                Operators::<T>::contains_key(owner, who)
+        }
+
+        /// Revoke `claim` in `namespace` on behalf of `who` after checking that `who` may do
+        /// so. Besides the owner and its operators, the admin of a namespace may revoke any of
+        /// its claims.
+        pub(crate) fn try_revoke(
+            who: T::AccountId,
+            namespace: NamespaceId,
+            claim: BoundedVec<u8, T::MaxClaimLength>,
+            reason: RevocationReason,
+        ) -> DispatchResult {
+            ensure!(reason != RevocationReason::Expired, Error::<T>::InvalidRevocationReason);
+            // 确保调用者是数据的所有者、已授权的操作人或命名空间管理员
+            let ClaimInfo { owner, status, .. } =
+                Proofs::<T>::get(namespace, &claim).ok_or(Error::<T>::ProofNotExist)?;
+            ensure!(
+                Self::is_owner_or_operator(&who, &owner, namespace, &claim) ||
+                    Namespaces::<T>::get(namespace).map_or(false, |details| details.admin == who),
+                Error::<T>::NotProofOwner
+            );
+
+            // 确保数据当前是有效状态
+            ensure!(status.is_active(), Error::<T>::ProofAlreadyRevoked);
+            ensure!(!Self::is_expired(namespace, &claim), Error::<T>::ProofExpired);
+
+            // 共有存证需要通过 approve_action 撤销
+            ensure!(!CoOwners::<T>::contains_key(namespace, &claim), Error::<T>::RequiresApproval);
+            ensure!(!Challenges::<T>::contains_key(namespace, &claim), Error::<T>::ClaimDisputed);
+            ensure!(!Self::is_locked(&status), Error::<T>::ClaimLocked);
+
+            Self::do_revoke(namespace, claim, owner, Some(who), reason)
+        }
+
+        /// Transfer `claim` in `namespace` from `sender` to `new_owner` after checking that
+        /// `sender` may do so.
+        pub(crate) fn try_transfer(
+            sender: T::AccountId,
+            namespace: NamespaceId,
+            claim: BoundedVec<u8, T::MaxClaimLength>,
+            new_owner: T::AccountId,
+        ) -> DispatchResult {
+            // 校验数据是否存在
+            let ClaimInfo { owner: current_owner, status, .. } =
+                Proofs::<T>::get(namespace, &claim).ok_or(Error::<T>::ProofNotExist)?;
+
+            // 确保调用者是当前所有者或已授权的操作人
+            ensure!(
+                Self::is_owner_or_operator(&sender, &current_owner, namespace, &claim),
+                Error::<T>::NotProofOwner
+            );
+
+            // 已撤销或已过期的存证不能转移
+            ensure!(status.is_active(), Error::<T>::ProofAlreadyRevoked);
+            ensure!(!Self::is_expired(namespace, &claim), Error::<T>::ProofExpired);
+
+            // 确保新所有者不同于当前所有者
+            ensure!(current_owner != new_owner, Error::<T>::CannotTransferToSelf);
+
+            // 共有存证需要通过 approve_action 转移
+            ensure!(!CoOwners::<T>::contains_key(namespace, &claim), Error::<T>::RequiresApproval);
+            ensure!(!Challenges::<T>::contains_key(namespace, &claim), Error::<T>::ClaimDisputed);
+            ensure!(!Self::is_locked(&status), Error::<T>::ClaimLocked);
+
+            Self::do_transfer(namespace, claim, current_owner, new_owner, false)
+        }
+
+        /// Apply `f` to every claim of a batch. In [`BatchMode::AllOrNothing`] the first failure
+        /// aborts the call; in [`BatchMode::BestEffort`] failed items are rolled back one by one
+        /// and reported in [`Event::BatchCompleted`].
+        ///
+        /// Returns the actual weight of the batch, as given by `weight` for the number of items
+        /// that succeeded, the number that failed and the total length of the claims processed.
+        pub(crate) fn run_batch(
+            claims: impl IntoIterator<Item = BoundedVec<u8, T::MaxClaimLength>>,
+            mode: BatchMode,
+            weight: impl Fn(u32, u32, u32) -> Weight,
+            mut f: impl FnMut(BoundedVec<u8, T::MaxClaimLength>) -> DispatchResult,
+        ) -> DispatchResultWithPostInfo {
+            let mut succeeded = 0u32;
+            let mut failures = Vec::new();
+            let mut bytes = 0u32;
+            for (index, claim) in claims.into_iter().enumerate() {
+                bytes.saturating_accrue(claim.len() as u32);
+                match mode {
+                    // 之后的项目未被处理，不收取其权重
+                    BatchMode::AllOrNothing => {
+                        f(claim).map_err(|error| error.with_weight(weight(succeeded, 1, bytes)))?
+                    },
+                    BatchMode::BestEffort => {
+                        if let Err(error) = with_storage_layer(|| f(claim)) {
+                            failures.push((index as u32, error));
+                            continue;
+                        }
+                    },
+                }
+                succeeded += 1;
+            }
+
+            let actual_weight = weight(succeeded, failures.len() as u32, bytes);
+            Self::deposit_event(Event::BatchCompleted { succeeded, failures });
+
+            Ok(Some(actual_weight).into())
+        }
+
+        /// The total length of the claims of a batch, which the batch weights depend on.
+        pub fn total_len(claims: &[BoundedVec<u8, T::MaxClaimLength>]) -> u32 {
+            claims.iter().map(|claim| claim.len() as u32).sum()
+        }
+
+        /// Record `claim` in `namespace` as owned by `who` if the re-registration policy allows
+        /// it, and emit the creation event. Callers are responsible for the origin checks.
+        pub(crate) fn do_create(
+            who: T::AccountId,
+            namespace: NamespaceId,
+            claim: BoundedVec<u8, T::MaxClaimLength>,
+            lifetime: Option<BlockNumberFor<T>>,
+            metadata: Option<ClaimMetadata<T>>,
+        ) -> DispatchResult {
+            // 已撤销的存证按重新登记策略处理
+            if let Some(tombstone) = Tombstones::<T>::get(namespace, &claim) {
+                ensure!(
+                    T::Reregistration::get().allows(&who, &tombstone),
+                    Error::<T>::ReregistrationNotAllowed
+                );
+            }
+            let timestamp = Self::now();
+            Self::insert_claim(who.clone(), namespace, claim.clone(), lifetime, metadata.clone(), timestamp)?;
+
+            if namespace == DEFAULT_NAMESPACE {
+                Self::deposit_event(Event::ClaimCreated { owner: who, claim, metadata, timestamp });
+            } else {
+                Self::deposit_event(Event::NamespacedClaimCreated { namespace, owner: who, claim, timestamp });
+            }
+            Ok(())
+        }
+
+        /// Record `claim` in `namespace` as owned by `who` at `timestamp` and hold its deposit,
+        /// replacing the tombstone of a revoked or expired claim without consulting the
+        /// re-registration policy. Emits no event; callers report the creation themselves.
+        fn insert_claim(
+            who: T::AccountId,
+            namespace: NamespaceId,
+            claim: BoundedVec<u8, T::MaxClaimLength>,
+            lifetime: Option<BlockNumberFor<T>>,
+            metadata: Option<ClaimMetadata<T>>,
+            timestamp: Moment,
+        ) -> DispatchResult {
+            ensure!{
+                Tombstones::<T>::contains_key(namespace, &claim) || !Proofs::<T>::contains_key(namespace, &claim),
+                Error::<T>::ProofAlreadyExist
+            };
+            ensure!(lifetime.map_or(true, |l| !l.is_zero()), Error::<T>::InvalidLifetime);
+            Self::add_owned_claim(&who, namespace, &claim)?;
+            Tombstones::<T>::remove(namespace, &claim);
+            Metadata::<T>::remove(namespace, &claim);
+            if let Some(ref metadata) = metadata {
+                Metadata::<T>::insert(namespace, &claim, metadata);
+            }
+            // 锁定存证押金（包含元数据的字节数）
+            let deposit = Self::deposit_for(namespace, &claim);
+            T::Currency::hold(&HoldReason::ClaimDeposit.into(), &who, deposit)
+                .map_err(|_| Error::<T>::InsufficientDeposit)?;
+            Deposits::<T>::insert(namespace, &claim, deposit);
+            let now = frame_system::Pallet::<T>::block_number();
+            // 设置存证有效期
+            if let Some(lifetime) = lifetime {
+                Self::schedule_expiry(namespace, &claim, now.saturating_add(lifetime));
+            }
+            let is_new = !Proofs::<T>::contains_key(namespace, &claim);
+            Proofs::<T>::insert(
+                namespace,
+                &claim,
+                ClaimInfo {
+                    owner: who.clone(),
+                    created_at: now,
+                    updated_at: now,
+                    created_time: timestamp,
+                    updated_time: timestamp,
+                    status: ClaimStatus::Active,
+                },
+            );
+            if is_new {
+                ClaimCount::<T>::mutate(|count| count.saturating_inc());
+            }
+            Self::record_history(namespace, &claim, None, Some(who), ProvenanceAction::Created, timestamp);
+
+            Ok(())
+        }
+
+        /// Move `claim` in `namespace` from `current_owner` to `new_owner`, together with its
+        /// deposit and its entry in the owner index. A pending challenge ends and its bond is
+        /// returned. `forced` transfers, made by `ForceOrigin`, emit
+        /// [`Event::ClaimForceTransferred`] instead of the regular transfer event. Callers are
+        /// responsible for the permission checks.
+        fn do_transfer(
+            namespace: NamespaceId,
+            claim: BoundedVec<u8, T::MaxClaimLength>,
+            current_owner: T::AccountId,
+            new_owner: T::AccountId,
+            forced: bool,
+        ) -> DispatchResult {
+            Self::add_owned_claim(&new_owner, namespace, &claim)?;
+            Self::remove_owned_claim(&current_owner, namespace, &claim);
+
+            // 押金由新所有者重新锁定，再退还给原所有者
+            let deposit = Self::deposit_for(namespace, &claim);
+            T::Currency::hold(&HoldReason::ClaimDeposit.into(), &new_owner, deposit)
+                .map_err(|_| Error::<T>::InsufficientDeposit)?;
+            if let Some(old_deposit) = Deposits::<T>::get(namespace, &claim) {
+                T::Currency::release(
+                    &HoldReason::ClaimDeposit.into(),
+                    &current_owner,
+                    old_deposit,
+                    Precision::BestEffort,
+                )?;
+            }
+            Deposits::<T>::insert(namespace, &claim, deposit);
+
+            // 更新存储，将所有权转移给新所有者，并清除待处理的转移要约和共有关系
+            let timestamp = Self::now();
+            Proofs::<T>::mutate(namespace, &claim, |info| {
+                if let Some(info) = info {
+                    info.owner = new_owner.clone();
+                    info.updated_at = frame_system::Pallet::<T>::block_number();
+                    info.updated_time = timestamp;
+                }
+            });
+            Self::take_offer(namespace, &claim);
+            ClaimOperators::<T>::remove(namespace, &claim);
+            CoOwners::<T>::remove(namespace, &claim);
+            Self::clear_approvals(namespace, &claim);
+            // 所有权已有定论，未决的质疑随之结束
+            Self::refund_challenge(namespace, &claim);
+            Self::record_history(
+                namespace,
+                &claim,
+                Some(current_owner.clone()),
+                Some(new_owner.clone()),
+                ProvenanceAction::Transferred,
+                timestamp,
+            );
+
+            if forced {
+                Self::deposit_event(Event::ClaimForceTransferred {
+                    old_owner: current_owner,
+                    new_owner,
+                    claim,
+                    timestamp,
+                });
+            } else if namespace == DEFAULT_NAMESPACE {
+                Self::deposit_event(Event::ClaimTransferred {
+                    old_owner: current_owner,
+                    new_owner,
+                    claim,
+                    timestamp,
+                });
+            } else {
+                Self::deposit_event(Event::NamespacedClaimTransferred {
+                    namespace,
+                    old_owner: current_owner,
+                    new_owner,
+                    claim,
+                    timestamp,
+                });
+            }
+
+            Ok(())
+        }
+
+        /// Mark `claim` in `namespace` as revoked by `revoker`, `None` for `ForceOrigin`, leave a
+        /// tombstone and release the deposit to `owner`. Revocations by `ForceOrigin` emit
+        /// [`Event::ClaimForceRevoked`] instead of the regular revocation event. Callers are
+        /// responsible for the permission checks.
+        fn do_revoke(
+            namespace: NamespaceId,
+            claim: BoundedVec<u8, T::MaxClaimLength>,
+            owner: T::AccountId,
+            revoker: Option<T::AccountId>,
+            reason: RevocationReason,
+        ) -> DispatchResult {
+            // 撤销后不再需要过期清理，未决的质疑也随之结束
+            Self::cancel_expiry(namespace, &claim);
+            Self::refund_challenge(namespace, &claim);
+            Self::remove_owned_claim(&owner, namespace, &claim);
+            Self::take_offer(namespace, &claim);
+            ClaimOperators::<T>::remove(namespace, &claim);
+            CoOwners::<T>::remove(namespace, &claim);
+            Self::clear_approvals(namespace, &claim);
+
+            // 更新状态为无效，并记录撤销原因
+            let forced = revoker.is_none();
+            let now = frame_system::Pallet::<T>::block_number();
+            let timestamp = Self::now();
+            Proofs::<T>::mutate(namespace, &claim, |info| {
+                if let Some(info) = info {
+                    info.status = ClaimStatus::Revoked;
+                    info.updated_at = now;
+                    info.updated_time = timestamp;
+                }
+            });
+            Tombstones::<T>::insert(
+                namespace,
+                &claim,
+                Tombstone { owner: owner.clone(), revoker, revoked_at: now, reason },
+            );
+            Self::record_history(
+                namespace,
+                &claim,
+                Some(owner.clone()),
+                None,
+                ProvenanceAction::Revoked,
+                timestamp,
+            );
+
+            // 退还存证押金
+            if let Some(deposit) = Deposits::<T>::take(namespace, &claim) {
+                T::Currency::release(
+                    &HoldReason::ClaimDeposit.into(),
+                    &owner,
+                    deposit,
+                    Precision::BestEffort,
+                )?;
+            }
+
+            // 触发撤回事件
+            if forced {
+                Self::deposit_event(Event::ClaimForceRevoked { owner, claim, reason });
+            } else if namespace == DEFAULT_NAMESPACE {
+                Self::deposit_event(Event::ClaimRevoked { owner, claim, reason });
+            } else {
+                Self::deposit_event(Event::NamespacedClaimRevoked { namespace, owner, claim, reason });
+            }
+
+            Ok(())
+        }
+
+        /// The current time in milliseconds since the Unix epoch.
+        fn now() -> Moment {
+            T::UnixTime::now().as_millis().saturated_into()
+        }
+
+        /// Lock `claim` in `namespace` until block `until`, letting `beneficiary` release it
+        /// early. Callers are responsible for the permission checks.
+        fn do_lock(
+            namespace: NamespaceId,
+            claim: BoundedVec<u8, T::MaxClaimLength>,
+            owner: T::AccountId,
+            until: BlockNumberFor<T>,
+            beneficiary: Option<T::AccountId>,
+        ) {
+            Self::set_status(namespace, &claim, ClaimStatus::Locked { until, beneficiary: beneficiary.clone() });
+            Self::deposit_event(Event::ClaimLocked { owner, claim, until, beneficiary });
+        }
+
+        /// Append an entry made at `timestamp` to the provenance history of `claim`, dropping the
+        /// oldest entry when the history is full.
+        fn record_history(
+            namespace: NamespaceId,
+            claim: &BoundedVec<u8, T::MaxClaimLength>,
+            from: Option<T::AccountId>,
+            to: Option<T::AccountId>,
+            action: ProvenanceAction,
+            timestamp: Moment,
+        ) {
+            let record = ProvenanceRecord {
+                from,
+                to,
+                block: frame_system::Pallet::<T>::block_number(),
+                timestamp,
+                action,
+            };
+            ClaimHistory::<T>::mutate(namespace, claim, |history| {
+                if history.is_full() {
+                    history.remove(0);
+                }
+                // 已腾出空间，不会失败
+                let _ = history.try_push(record);
+            });
+        }
+
+        fn add_owned_claim(
+            who: &T::AccountId,
+            namespace: NamespaceId,
+            claim: &BoundedVec<u8, T::MaxClaimLength>,
+        ) -> DispatchResult {
+            OwnerClaimCount::<T>::try_mutate(who, |count| -> DispatchResult {
+                ensure!(*count < T::MaxClaimsPerOwner::get(), Error::<T>::TooManyClaims);
+                *count += 1;
+                Ok(())
+            })?;
+            ClaimsByOwner::<T>::insert(who, (namespace, claim), ());
+            Ok(())
+        }
+
+        fn remove_owned_claim(
+            who: &T::AccountId,
+            namespace: NamespaceId,
+            claim: &BoundedVec<u8, T::MaxClaimLength>,
+        ) {
+            if ClaimsByOwner::<T>::take(who, (namespace, claim)).is_some() {
+                OwnerClaimCount::<T>::mutate_exists(who, |count| {
+                    *count = count.and_then(|c| c.checked_sub(1)).filter(|c| *c > 0);
+                });
+            }
+        }
+
+        fn schedule_expiry(
+            namespace: NamespaceId,
+            claim: &BoundedVec<u8, T::MaxClaimLength>,
+            expires_at: BlockNumberFor<T>,
+        ) {
+            Expiries::<T>::insert(namespace, claim, expires_at);
+            ExpiryQueue::<T>::insert(expires_at, (namespace, claim), ());
+            NextExpiryCheck::<T>::mutate(|cursor| {
+                *cursor = Some(cursor.map_or(expires_at, |cursor| cursor.min(expires_at)))
+            });
+        }
+
+        fn cancel_expiry(namespace: NamespaceId, claim: &BoundedVec<u8, T::MaxClaimLength>) {
+            if let Some(expires_at) = Expiries::<T>::take(namespace, claim) {
+                ExpiryQueue::<T>::remove(expires_at, (namespace, claim));
+            }
+        }
+
+        /// Remove the approval of `who` from whichever action of `claim` it backs.
+        fn withdraw_approval(
+            namespace: NamespaceId,
+            claim: &BoundedVec<u8, T::MaxClaimLength>,
+            who: &T::AccountId,
+        ) {
+            let pending: Vec<_> = Approvals::<T>::iter_prefix((namespace, claim.clone())).collect();
+            for (action_hash, mut approval) in pending {
+                if !approval.approvals.contains(who) {
+                    continue;
+                }
+                approval.approvals.retain(|approver| approver != who);
+                if approval.approvals.is_empty() {
+                    Approvals::<T>::remove((namespace, claim, action_hash));
+                } else {
+                    Approvals::<T>::insert((namespace, claim, action_hash), approval);
+                }
+            }
+        }
+
+        fn clear_approvals(namespace: NamespaceId, claim: &BoundedVec<u8, T::MaxClaimLength>) {
+            // 每个共有人至多支持一项操作，条目数不超过 MaxCoOwners
+            let _ = Approvals::<T>::clear_prefix((namespace, claim.clone()), T::MaxCoOwners::get(), None);
+        }
+
+        /// Remove the rate-limit windows that ended at or before `now`, walking the window queue
+        /// in block order until `limit` is used up. Returns the weight consumed.
+        pub(crate) fn purge_rate_windows(now: BlockNumberFor<T>, limit: Weight) -> Weight {
+            let db = T::DbWeight::get();
+            let mut meter = WeightMeter::with_limit(limit);
+            if meter.try_consume(db.reads_writes(1, 1)).is_err() {
+                return Weight::zero();
+            }
+
+            let Some(mut cursor) = NextRateWindowCheck::<T>::get() else {
+                return meter.consumed();
+            };
+            while cursor <= now {
+                match RateWindowQueue::<T>::iter_key_prefix(cursor).next() {
+                    Some(who) => {
+                        if meter.try_consume(db.reads_writes(1, 2)).is_err() {
+                            break;
+                        }
+                        RateWindowQueue::<T>::remove(cursor, &who);
+                        ClaimRateWindows::<T>::remove(&who);
+                    },
+                    None => {
+                        if meter.try_consume(db.reads(1)).is_err() {
+                            break;
+                        }
+                        cursor.saturating_inc();
+                    },
+                }
+            }
+            NextRateWindowCheck::<T>::put(cursor);
+
+            meter.consumed()
+        }
+
+        fn put_offer(
+            namespace: NamespaceId,
+            claim: &BoundedVec<u8, T::MaxClaimLength>,
+            offer: PendingTransfer<T::AccountId, BlockNumberFor<T>>,
+        ) {
+            OfferQueue::<T>::insert(offer.expires_at, (namespace, claim), ());
+            NextOfferCheck::<T>::mutate(|cursor| {
+                *cursor = Some(cursor.map_or(offer.expires_at, |cursor| cursor.min(offer.expires_at)))
+            });
+            PendingTransfers::<T>::insert(namespace, claim, offer);
+        }
+
+        /// Remove the pending offer of `claim` from storage and from the offer queue.
+        fn take_offer(
+            namespace: NamespaceId,
+            claim: &BoundedVec<u8, T::MaxClaimLength>,
+        ) -> Option<PendingTransfer<T::AccountId, BlockNumberFor<T>>> {
+            let offer = PendingTransfers::<T>::take(namespace, claim)?;
+            OfferQueue::<T>::remove(offer.expires_at, (namespace, claim));
+            Some(offer)
+        }
+
+        /// Remove the challenge of `claim` from storage and from the expiry queue.
+        fn take_challenge(
+            namespace: NamespaceId,
+            claim: &BoundedVec<u8, T::MaxClaimLength>,
+        ) -> Option<Challenge<T::AccountId, BalanceOf<T>, BlockNumberFor<T>>> {
+            let challenge = Challenges::<T>::take(namespace, claim)?;
+            ChallengeQueue::<T>::remove(challenge.expires_at, (namespace, claim));
+            Some(challenge)
+        }
+
+        /// Drop the challenge of `claim`, if any, and return its bond to the challenger.
+        fn refund_challenge(
+            namespace: NamespaceId,
+            claim: &BoundedVec<u8, T::MaxClaimLength>,
+        ) -> Option<Challenge<T::AccountId, BalanceOf<T>, BlockNumberFor<T>>> {
+            let challenge = Self::take_challenge(namespace, claim)?;
+            // 保证金按 BestEffort 释放，不会失败
+            let _ = T::Currency::release(
+                &HoldReason::ChallengeBond.into(),
+                &challenge.challenger,
+                challenge.bond,
+                Precision::BestEffort,
+            );
+            Some(challenge)
+        }
+
+        /// Return the bonds of the challenges expiring at `now`. Every block is visited, so the
+        /// queue never falls behind.
+        pub(crate) fn expire_challenges(now: BlockNumberFor<T>) -> Weight {
+            let mut weight = T::DbWeight::get().reads(1);
+            let claims: Vec<_> = ChallengeQueue::<T>::iter_key_prefix(now).collect();
+            for (namespace, claim) in claims {
+                if let Some(challenge) = Self::refund_challenge(namespace, &claim) {
+                    Self::deposit_event(Event::ChallengeExpired { challenger: challenge.challenger, claim });
+                }
+                weight.saturating_accrue(T::WeightInfo::expire_challenge());
+            }
+            weight
+        }
+
+        /// Check the invariants that tie the storage items of the pallet together: the claim
+        /// counters and the owner index, the expiry queue, and the deposits and bonds against the
+        /// balances actually held.
+        #[cfg(any(feature = "try-runtime", test))]
+        pub fn do_try_state() -> Result<(), frame_support::sp_runtime::TryRuntimeError> {
+            use alloc::collections::BTreeMap;
+            use frame_support::traits::fungible::InspectHold;
+
+            // 计数器与存证总数一致
+            ensure!(
+                ClaimCount::<T>::get() == Proofs::<T>::iter_keys().count() as u64,
+                "ClaimCount does not match the number of claims"
+            );
+
+            // 所有者索引恰好包含各命名空间中的有效存证
+            for (owner, (namespace, claim), ()) in ClaimsByOwner::<T>::iter() {
+                let info = Proofs::<T>::get(namespace, &claim)
+                    .ok_or("the owner index lists a claim that does not exist")?;
+                ensure!(
+                    info.owner == owner && info.status.is_active(),
+                    "the owner index lists a claim under an account that does not own it"
+                );
+                ensure!(
+                    OwnerClaimCount::<T>::contains_key(&owner),
+                    "an account in the owner index has no claim count"
+                );
+            }
+            for (owner, count) in OwnerClaimCount::<T>::iter() {
+                ensure!(
+                    count as usize == ClaimsByOwner::<T>::iter_prefix(&owner).count(),
+                    "OwnerClaimCount does not match the owner index"
+                );
+            }
+            for (namespace, claim, info) in Proofs::<T>::iter() {
+                ensure!(
+                    !info.status.is_active() ||
+                        ClaimsByOwner::<T>::contains_key(&info.owner, (namespace, &claim)),
+                    "an active claim is missing from the owner index"
+                );
+            }
+
+            // 过期的存证都还在清理游标之后，on_idle 终会将其清除
+            let cursor = NextExpiryCheck::<T>::get();
+            for (namespace, claim, expires_at) in Expiries::<T>::iter() {
+                ensure!(
+                    ExpiryQueue::<T>::contains_key(expires_at, (namespace, &claim)),
+                    "a time-limited claim is missing from the expiry queue"
+                );
+                ensure!(
+                    cursor.map_or(false, |cursor| cursor <= expires_at),
+                    "a claim past its expiry is behind the purge cursor"
+                );
+                ensure!(
+                    Proofs::<T>::get(namespace, &claim).map_or(false, |info| info.status.is_active()),
+                    "an expiry is scheduled for a claim that is not active"
+                );
+            }
+            for (expires_at, (namespace, claim), ()) in ExpiryQueue::<T>::iter() {
+                ensure!(
+                    Expiries::<T>::get(namespace, &claim) == Some(expires_at),
+                    "the expiry queue does not match Expiries"
+                );
+            }
+
+            // 只有共有人可以批准共有存证的操作，且每人至多支持一项操作
+            let mut approvers = BTreeMap::<(NamespaceId, BoundedVec<u8, T::MaxClaimLength>), Vec<T::AccountId>>::new();
+            for ((namespace, claim, _), approval) in Approvals::<T>::iter() {
+                let joint = CoOwners::<T>::get(namespace, &claim)
+                    .ok_or("approvals are pending on a claim that is not jointly owned")?;
+                ensure!(
+                    approval.approvals.iter().all(|approver| joint.co_owners.contains(approver)),
+                    "an approval was given by an account that is not a co-owner"
+                );
+                approvers.entry((namespace, claim)).or_default().extend(approval.approvals);
+            }
+            for (_, mut accounts) in approvers {
+                let total = accounts.len();
+                accounts.sort();
+                accounts.dedup();
+                ensure!(accounts.len() == total, "a co-owner backs more than one action of a claim");
+            }
+
+            // 每个限流窗口都在窗口队列中，结束后终会被清除
+            let window_cursor = NextRateWindowCheck::<T>::get();
+            for (who, (start, _)) in ClaimRateWindows::<T>::iter() {
+                let ends_at = start.saturating_add(T::WindowLength::get());
+                ensure!(
+                    RateWindowQueue::<T>::contains_key(ends_at, &who),
+                    "a rate-limit window is missing from the window queue"
+                );
+                ensure!(
+                    window_cursor.map_or(false, |cursor| cursor <= ends_at),
+                    "an ended rate-limit window is behind the window cursor"
+                );
+            }
+            ensure!(
+                RateWindowQueue::<T>::iter_keys().count() == ClaimRateWindows::<T>::iter_keys().count(),
+                "the window queue does not match ClaimRateWindows"
+            );
+
+            // 要约队列与待处理的要约一一对应
+            let offer_cursor = NextOfferCheck::<T>::get();
+            for (namespace, claim, offer) in PendingTransfers::<T>::iter() {
+                ensure!(
+                    OfferQueue::<T>::contains_key(offer.expires_at, (namespace, &claim)),
+                    "a transfer offer is missing from the offer queue"
+                );
+                ensure!(
+                    offer_cursor.map_or(false, |cursor| cursor <= offer.expires_at),
+                    "a transfer offer past its deadline is behind the offer cursor"
+                );
+            }
+            ensure!(
+                OfferQueue::<T>::iter_keys().count() == PendingTransfers::<T>::iter_keys().count(),
+                "the offer queue does not match PendingTransfers"
+            );
+
+            // 记录的押金与实际锁定的余额一致
+            let mut deposits = BTreeMap::<T::AccountId, BalanceOf<T>>::new();
+            for (namespace, claim, info) in Proofs::<T>::iter() {
+                let deposit = Deposits::<T>::get(namespace, &claim);
+                ensure!(
+                    deposit.is_some() == info.status.is_active(),
+                    "only active claims must have a deposit"
+                );
+                if let Some(deposit) = deposit {
+                    let total = deposits.entry(info.owner).or_default();
+                    *total = total.saturating_add(deposit);
+                }
+            }
+            ensure!(
+                Deposits::<T>::iter_keys().all(|(namespace, claim)| Proofs::<T>::contains_key(namespace, claim)),
+                "a deposit is recorded for a claim that does not exist"
+            );
+            // 锚定 Merkle 根和内容存证的押金同样以 ClaimDeposit 名义锁定
+            for root in Roots::<T>::iter_values() {
+                let total = deposits.entry(root.owner).or_default();
+                *total = total.saturating_add(root.deposit);
+            }
+            for claim in HashedClaims::<T>::iter_values() {
+                let total = deposits.entry(claim.owner).or_default();
+                *total = total.saturating_add(claim.deposit);
+            }
+            let reason = HoldReason::ClaimDeposit.into();
+            for (owner, total) in deposits {
+                ensure!(
+                    T::Currency::balance_on_hold(&reason, &owner) == total,
+                    "the claim and root deposits of an account do not match its held balance"
+                );
+            }
+
+            // 质疑保证金同样与锁定余额一致
+            let mut bonds = BTreeMap::<T::AccountId, BalanceOf<T>>::new();
+            for (namespace, claim, challenge) in Challenges::<T>::iter() {
+                ensure!(
+                    ChallengeQueue::<T>::contains_key(challenge.expires_at, (namespace, &claim)),
+                    "a challenge is missing from the challenge queue"
+                );
+                ensure!(
+                    Proofs::<T>::get(namespace, &claim).map_or(false, |info| info.status.is_active()),
+                    "a challenge is pending on a claim that is not active"
+                );
+                let total = bonds.entry(challenge.challenger).or_default();
+                *total = total.saturating_add(challenge.bond);
+            }
+            ensure!(
+                ChallengeQueue::<T>::iter_keys().count() == Challenges::<T>::iter_keys().count(),
+                "the challenge queue does not match Challenges"
+            );
+            let reason = HoldReason::ChallengeBond.into();
+            for (challenger, total) in bonds {
+                ensure!(
+                    T::Currency::balance_on_hold(&reason, &challenger) == total,
+                    "the challenge bonds of an account do not match its held balance"
+                );
+            }
+
+            Ok(())
+        }
+
+        /// Purge claims that expired at or before `now`, walking the expiry queue in block order
+        /// until `limit` is used up. Returns the weight consumed.
+        pub(crate) fn purge_expired(now: BlockNumberFor<T>, limit: Weight) -> Weight {
+            let db = T::DbWeight::get();
+            let mut meter = WeightMeter::with_limit(limit);
+            if meter.try_consume(db.reads_writes(1, 1)).is_err() {
+                return Weight::zero();
+            }
+
+            // 游标在首次登记过期时设置，避免扫描历史区块
+            let Some(mut cursor) = NextExpiryCheck::<T>::get() else {
+                return meter.consumed();
+            };
+            while cursor <= now {
+                match ExpiryQueue::<T>::iter_key_prefix(cursor).next() {
+                    Some((namespace, claim)) => {
+                        if meter.try_consume(db.reads_writes(8, 19)).is_err() {
+                            break;
+                        }
+                        Self::purge_claim(cursor, namespace, claim);
+                    },
+                    None => {
+                        if meter.try_consume(db.reads(1)).is_err() {
+                            break;
+                        }
+                        cursor.saturating_inc();
+                    },
+                }
+            }
+            NextExpiryCheck::<T>::put(cursor);
+
+            meter.consumed()
+        }
+
+        /// Remove transfer offers that expired at or before `now`, walking the offer queue in
+        /// block order until `limit` is used up. Returns the weight consumed.
+        pub(crate) fn purge_offers(now: BlockNumberFor<T>, limit: Weight) -> Weight {
+            let db = T::DbWeight::get();
+            let mut meter = WeightMeter::with_limit(limit);
+            if meter.try_consume(db.reads_writes(1, 1)).is_err() {
+                return Weight::zero();
+            }
+
+            let Some(mut cursor) = NextOfferCheck::<T>::get() else {
+                return meter.consumed();
+            };
+            while cursor <= now {
+                match OfferQueue::<T>::iter_key_prefix(cursor).next() {
+                    Some((namespace, claim)) => {
+                        if meter.try_consume(db.reads_writes(1, 2)).is_err() {
+                            break;
+                        }
+                        OfferQueue::<T>::remove(cursor, (namespace, &claim));
+                        if let Some(offer) = PendingTransfers::<T>::take(namespace, &claim) {
+                            Self::deposit_event(Event::OfferExpired { to: offer.to, claim });
+                        }
+                    },
+                    None => {
+                        if meter.try_consume(db.reads(1)).is_err() {
+                            break;
+                        }
+                        cursor.saturating_inc();
+                    },
+                }
+            }
+            NextOfferCheck::<T>::put(cursor);
+
+            meter.consumed()
+        }
+
+        fn purge_claim(
+            expires_at: BlockNumberFor<T>,
+            namespace: NamespaceId,
+            claim: BoundedVec<u8, T::MaxClaimLength>,
+        ) {
+            ExpiryQueue::<T>::remove(expires_at, (namespace, &claim));
+            Expiries::<T>::remove(namespace, &claim);
+            if let Some(ClaimInfo { owner, .. }) = Proofs::<T>::take(namespace, &claim) {
+                ClaimCount::<T>::mutate(|count| count.saturating_dec());
+                Self::take_offer(namespace, &claim);
+                ClaimOperators::<T>::remove(namespace, &claim);
+                CoOwners::<T>::remove(namespace, &claim);
+                Self::clear_approvals(namespace, &claim);
+                Metadata::<T>::remove(namespace, &claim);
+                // 保留历史记录，存证过期后仍可追溯其归属
+                Self::record_history(
+                    namespace,
+                    &claim,
+                    Some(owner.clone()),
+                    None,
+                    ProvenanceAction::Expired,
+                    Self::now(),
+                );
+                Self::refund_challenge(namespace, &claim);
+                Self::remove_owned_claim(&owner, namespace, &claim);
+                // 过期的存证同样留下墓碑，防止被他人抢注
+                Tombstones::<T>::insert(
+                    namespace,
+                    &claim,
+                    Tombstone {
+                        owner: owner.clone(),
+                        revoker: None,
+                        revoked_at: frame_system::Pallet::<T>::block_number(),
+                        reason: RevocationReason::Expired,
+                    },
+                );
+                if let Some(deposit) = Deposits::<T>::take(namespace, &claim) {
+                    // 押金按 BestEffort 释放，不会失败
+                    let _ = T::Currency::release(
+                        &HoldReason::ClaimDeposit.into(),
+                        &owner,
+                        deposit,
+                        Precision::BestEffort,
+                    );
+                }
+                Self::deposit_event(Event::ClaimExpired { owner, claim });
+            }
+        }
+    }
+}
@@ -0,0 +1,594 @@
+//! Storage migrations of the PoE pallet.
+
+use crate::{
+    Approvals, BalanceOf, Challenge, ChallengeQueue, Challenges, ClaimCount, ClaimHistory,
+    ClaimInfo, ClaimMetadata, ClaimOperators, ClaimStatus, ClaimsByOwner, CoOwners, Config,
+    Deposits, Expiries, ExpiryQueue, HoldReason, JointOwnership, Metadata, NextOfferCheck,
+    OfferQueue, OwnerClaimCount, Pallet, PendingApproval, PendingTransfer, PendingTransfers,
+    Proofs, ProvenanceAction, ProvenanceRecord, Tombstone, Tombstones,
+    DEFAULT_NAMESPACE,
+};
+use alloc::vec::Vec;
+use codec::FullCodec;
+use frame_support::{
+    migrations::VersionedMigration,
+    pallet_prelude::*,
+    sp_runtime::traits::{Hash, Zero},
+    storage::IterableStorageMap,
+    traits::{
+        fungible::{Inspect, MutateHold},
+        tokens::{Fortitude, Preservation},
+        OnRuntimeUpgrade,
+    },
+    BoundedVec,
+};
+use frame_system::pallet_prelude::BlockNumberFor;
+
+#[cfg(feature = "try-runtime")]
+use frame_support::sp_runtime::TryRuntimeError;
+
+/// The storage layout before [`v1`].
+pub mod v0 {
+    use super::*;
+
+    /// `Proofs` values were `(owner, block, is_active)` tuples. The block is the creation block
+    /// of active claims and the revocation block of revoked ones.
+    #[frame_support::storage_alias]
+    pub type Proofs<T: Config> = StorageMap<
+        Pallet<T>,
+        Blake2_128Concat,
+        BoundedVec<u8, <T as Config>::MaxClaimLength>,
+        (<T as frame_system::Config>::AccountId, BlockNumberFor<T>, bool),
+    >;
+}
+
+/// Storage version 1: `Proofs` values are [`ClaimInfo`](v1::ClaimInfo) records without times.
+pub mod v1 {
+    use super::*;
+
+    /// The record stored for every claim in storage version 1.
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, Debug, TypeInfo, MaxEncodedLen)]
+    pub struct ClaimInfo<AccountId, BlockNumber> {
+        pub owner: AccountId,
+        pub created_at: BlockNumber,
+        pub updated_at: BlockNumber,
+        pub status: ClaimStatus<AccountId, BlockNumber>,
+    }
+
+    /// A provenance record of storage version 1.
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, Debug, TypeInfo, MaxEncodedLen)]
+    pub struct ProvenanceRecord<AccountId, BlockNumber> {
+        pub from: Option<AccountId>,
+        pub to: Option<AccountId>,
+        pub block: BlockNumber,
+        pub action: ProvenanceAction,
+    }
+
+    #[frame_support::storage_alias]
+    pub type Proofs<T: Config> = StorageMap<
+        Pallet<T>,
+        Blake2_128Concat,
+        BoundedVec<u8, <T as Config>::MaxClaimLength>,
+        ClaimInfo<<T as frame_system::Config>::AccountId, BlockNumberFor<T>>,
+    >;
+
+    #[frame_support::storage_alias]
+    pub type ClaimHistory<T: Config> = StorageMap<
+        Pallet<T>,
+        Blake2_128Concat,
+        BoundedVec<u8, <T as Config>::MaxClaimLength>,
+        BoundedVec<
+            ProvenanceRecord<<T as frame_system::Config>::AccountId, BlockNumberFor<T>>,
+            <T as Config>::MaxHistoryLength,
+        >,
+        ValueQuery,
+    >;
+
+    /// Converts every `Proofs` tuple into a [`ClaimInfo`]. Runs unconditionally; use
+    /// [`MigrateV0ToV1`] instead, which only runs it on storage version 0.
+    pub struct VersionUncheckedMigrateV0ToV1<T>(PhantomData<T>);
+
+    impl<T: Config> OnRuntimeUpgrade for VersionUncheckedMigrateV0ToV1<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let mut translated = 0u64;
+            Proofs::<T>::translate::<(T::AccountId, BlockNumberFor<T>, bool), _>(
+                |_, (owner, block, is_active)| {
+                    translated += 1;
+                    // 旧格式只记录了一个区块号，同时用作创建和更新时间
+                    let status = if is_active { ClaimStatus::Active } else { ClaimStatus::Revoked };
+                    Some(ClaimInfo { owner, created_at: block, updated_at: block, status })
+                },
+            );
+
+            T::DbWeight::get().reads_writes(translated, translated)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+            let keys = v0::Proofs::<T>::iter_keys().count() as u64;
+            let values: Vec<_> = v0::Proofs::<T>::iter_values().collect();
+            ensure!(values.len() as u64 == keys, "some Proofs entries are not v0 tuples");
+            let active = values.iter().filter(|(_, _, is_active)| *is_active).count() as u64;
+
+            Ok((keys, active).encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+            let (keys, active): (u64, u64) =
+                Decode::decode(&mut &state[..]).map_err(|_| "invalid pre_upgrade state")?;
+            let values: Vec<_> = Proofs::<T>::iter_values().collect();
+            ensure!(values.len() as u64 == keys, "Proofs entries were lost in the migration");
+            ensure!(
+                values.iter().filter(|info| info.status.is_active()).count() as u64 == active,
+                "the number of active claims changed in the migration"
+            );
+
+            Ok(())
+        }
+    }
+
+    /// Migrates `Proofs` from storage version 0 to 1.
+    pub type MigrateV0ToV1<T> = VersionedMigration<
+        0,
+        1,
+        VersionUncheckedMigrateV0ToV1<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
+
+/// Storage version 2: claim records and provenance records carry a wall-clock time.
+pub mod v2 {
+    use super::*;
+
+    #[frame_support::storage_alias]
+    pub type Proofs<T: Config> = StorageMap<
+        Pallet<T>,
+        Blake2_128Concat,
+        BoundedVec<u8, <T as Config>::MaxClaimLength>,
+        ClaimInfo<<T as frame_system::Config>::AccountId, BlockNumberFor<T>>,
+    >;
+
+    #[frame_support::storage_alias]
+    pub type Deposits<T: Config> = StorageMap<
+        Pallet<T>,
+        Blake2_128Concat,
+        BoundedVec<u8, <T as Config>::MaxClaimLength>,
+        BalanceOf<T>,
+    >;
+
+    #[frame_support::storage_alias]
+    pub type Expiries<T: Config> = StorageMap<
+        Pallet<T>,
+        Blake2_128Concat,
+        BoundedVec<u8, <T as Config>::MaxClaimLength>,
+        BlockNumberFor<T>,
+    >;
+
+    #[frame_support::storage_alias]
+    pub type ExpiryQueue<T: Config> = StorageDoubleMap<
+        Pallet<T>,
+        Twox64Concat,
+        BlockNumberFor<T>,
+        Blake2_128Concat,
+        BoundedVec<u8, <T as Config>::MaxClaimLength>,
+        (),
+    >;
+
+    #[frame_support::storage_alias]
+    pub type ClaimsByOwner<T: Config> = StorageDoubleMap<
+        Pallet<T>,
+        Blake2_128Concat,
+        <T as frame_system::Config>::AccountId,
+        Blake2_128Concat,
+        BoundedVec<u8, <T as Config>::MaxClaimLength>,
+        (),
+    >;
+
+    #[frame_support::storage_alias]
+    pub type PendingTransfers<T: Config> = StorageMap<
+        Pallet<T>,
+        Blake2_128Concat,
+        BoundedVec<u8, <T as Config>::MaxClaimLength>,
+        PendingTransfer<<T as frame_system::Config>::AccountId, BlockNumberFor<T>>,
+    >;
+
+    #[frame_support::storage_alias]
+    pub type CoOwners<T: Config> = StorageMap<
+        Pallet<T>,
+        Blake2_128Concat,
+        BoundedVec<u8, <T as Config>::MaxClaimLength>,
+        JointOwnership<T>,
+    >;
+
+    #[frame_support::storage_alias]
+    pub type Approvals<T: Config> = StorageMap<
+        Pallet<T>,
+        Blake2_128Concat,
+        BoundedVec<u8, <T as Config>::MaxClaimLength>,
+        PendingApproval<T>,
+    >;
+
+    #[frame_support::storage_alias]
+    pub type Metadata<T: Config> = StorageMap<
+        Pallet<T>,
+        Blake2_128Concat,
+        BoundedVec<u8, <T as Config>::MaxClaimLength>,
+        ClaimMetadata<T>,
+    >;
+
+    #[frame_support::storage_alias]
+    pub type ClaimHistory<T: Config> = StorageMap<
+        Pallet<T>,
+        Blake2_128Concat,
+        BoundedVec<u8, <T as Config>::MaxClaimLength>,
+        BoundedVec<
+            ProvenanceRecord<<T as frame_system::Config>::AccountId, BlockNumberFor<T>>,
+            <T as Config>::MaxHistoryLength,
+        >,
+        ValueQuery,
+    >;
+
+    #[frame_support::storage_alias]
+    pub type Tombstones<T: Config> = StorageMap<
+        Pallet<T>,
+        Blake2_128Concat,
+        BoundedVec<u8, <T as Config>::MaxClaimLength>,
+        Tombstone<<T as frame_system::Config>::AccountId, BlockNumberFor<T>>,
+    >;
+
+    #[frame_support::storage_alias]
+    pub type ClaimOperators<T: Config> = StorageMap<
+        Pallet<T>,
+        Blake2_128Concat,
+        BoundedVec<u8, <T as Config>::MaxClaimLength>,
+        <T as frame_system::Config>::AccountId,
+    >;
+
+    #[frame_support::storage_alias]
+    pub type Challenges<T: Config> = StorageMap<
+        Pallet<T>,
+        Blake2_128Concat,
+        BoundedVec<u8, <T as Config>::MaxClaimLength>,
+        Challenge<<T as frame_system::Config>::AccountId, BalanceOf<T>, BlockNumberFor<T>>,
+    >;
+
+    /// The index of claims created from content, which kept the claims themselves in `Proofs`.
+    /// Cleared in [`v3`], where [`HashedClaims`](crate::HashedClaims) stores the claims under the
+    /// same name. Content claims created before v3 stay in `Proofs` as ordinary claims.
+    #[frame_support::storage_alias]
+    pub type HashedClaims<T: Config> =
+        StorageMap<Pallet<T>, Identity, <T as frame_system::Config>::Hash, ()>;
+
+    #[frame_support::storage_alias]
+    pub type ChallengeQueue<T: Config> = StorageDoubleMap<
+        Pallet<T>,
+        Twox64Concat,
+        BlockNumberFor<T>,
+        Blake2_128Concat,
+        BoundedVec<u8, <T as Config>::MaxClaimLength>,
+        (),
+    >;
+
+    /// Adds the times to every `Proofs` and `ClaimHistory` entry. The time of existing entries is
+    /// unknown and left at 0. Runs unconditionally; use [`MigrateV1ToV2`] instead, which only runs
+    /// it on storage version 1.
+    pub struct VersionUncheckedMigrateV1ToV2<T>(PhantomData<T>);
+
+    impl<T: Config> OnRuntimeUpgrade for VersionUncheckedMigrateV1ToV2<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let mut translated = 0u64;
+            Proofs::<T>::translate::<v1::ClaimInfo<T::AccountId, BlockNumberFor<T>>, _>(|_, old| {
+                translated += 1;
+                Some(ClaimInfo {
+                    owner: old.owner,
+                    created_at: old.created_at,
+                    updated_at: old.updated_at,
+                    created_time: 0,
+                    updated_time: 0,
+                    status: old.status,
+                })
+            });
+            ClaimHistory::<T>::translate::<
+                BoundedVec<v1::ProvenanceRecord<T::AccountId, BlockNumberFor<T>>, T::MaxHistoryLength>,
+                _,
+            >(|_, old| {
+                translated += 1;
+                let records = old
+                    .into_iter()
+                    .map(|r| ProvenanceRecord {
+                        from: r.from,
+                        to: r.to,
+                        block: r.block,
+                        timestamp: 0,
+                        action: r.action,
+                    })
+                    .collect::<Vec<_>>();
+                // 记录条数不变，不会超出上限
+                Some(BoundedVec::truncate_from(records))
+            });
+
+            T::DbWeight::get().reads_writes(translated, translated)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+            let keys = v1::Proofs::<T>::iter_keys().count() as u64;
+            let values = v1::Proofs::<T>::iter_values().count() as u64;
+            ensure!(values == keys, "some Proofs entries are not v1 records");
+
+            Ok(keys.encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+            let keys: u64 = Decode::decode(&mut &state[..]).map_err(|_| "invalid pre_upgrade state")?;
+            ensure!(
+                Proofs::<T>::iter_values().count() as u64 == keys,
+                "Proofs entries were lost in the migration"
+            );
+
+            Ok(())
+        }
+    }
+
+    /// Migrates `Proofs` and `ClaimHistory` from storage version 1 to 2.
+    pub type MigrateV1ToV2<T> = VersionedMigration<
+        1,
+        2,
+        VersionUncheckedMigrateV1ToV2<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
+
+/// Storage version 3: every per-claim storage item is keyed by namespace and claim.
+pub mod v3 {
+    use super::*;
+
+    /// Moves every entry of the claim-keyed map `Old` into [`DEFAULT_NAMESPACE`] of `New`, and
+    /// returns the number of entries moved.
+    fn move_into_default_namespace<T, V, Old, New>() -> u64
+    where
+        T: Config,
+        V: FullCodec,
+        Old: IterableStorageMap<BoundedVec<u8, T::MaxClaimLength>, V>,
+        New: frame_support::storage::StorageDoubleMap<
+            crate::NamespaceId,
+            BoundedVec<u8, T::MaxClaimLength>,
+            V,
+        >,
+    {
+        // 新旧键位于同一存储前缀下，须先取出全部旧记录再写入
+        let entries: Vec<_> = Old::drain().collect();
+        for (claim, value) in &entries {
+            New::insert(DEFAULT_NAMESPACE, claim, value);
+        }
+        entries.len() as u64
+    }
+
+    /// The number of entries of each storage item moved by the migration.
+    #[cfg(feature = "try-runtime")]
+    #[derive(Encode, Decode)]
+    struct Counts {
+        proofs: u64,
+        deposits: u64,
+        side_maps: u64,
+        queued: u64,
+    }
+
+    /// Rebuilds the owner index, the claim counters and the deposits from `Proofs`. Storage
+    /// versions before 3 did not maintain them for every claim: claims created before they were
+    /// introduced are missing from the index and hold no deposit. Returns the number of reads
+    /// and writes.
+    fn rebuild_indexes<T: Config>() -> (u64, u64) {
+        let (mut reads, mut writes) = (0u64, 0u64);
+        // 旧版本的索引不完整，清空后按存证记录重建
+        let cleared = v2::ClaimsByOwner::<T>::clear(u32::MAX, None).unique as u64 +
+            OwnerClaimCount::<T>::clear(u32::MAX, None).unique as u64;
+        writes += cleared;
+
+        let mut claims = 0u64;
+        for (namespace, claim, info) in Proofs::<T>::iter() {
+            claims += 1;
+            reads += 1;
+            if !info.status.is_active() {
+                continue;
+            }
+            ClaimsByOwner::<T>::insert(&info.owner, (namespace, &claim), ());
+            OwnerClaimCount::<T>::mutate(&info.owner, |count| *count = count.saturating_add(1));
+            reads += 2;
+            writes += 2;
+
+            if Deposits::<T>::contains_key(namespace, &claim) {
+                continue;
+            }
+            // 补锁押金；余额不足时锁定可用的部分，押金记录与实际锁定一致
+            let mut deposit = Pallet::<T>::deposit_for(namespace, &claim).min(
+                T::Currency::reducible_balance(&info.owner, Preservation::Preserve, Fortitude::Polite),
+            );
+            if !deposit.is_zero() &&
+                T::Currency::hold(&HoldReason::ClaimDeposit.into(), &info.owner, deposit).is_err()
+            {
+                deposit = Zero::zero();
+            }
+            Deposits::<T>::insert(namespace, &claim, deposit);
+            reads += 2;
+            writes += 2;
+        }
+        ClaimCount::<T>::put(claims);
+        writes += 1;
+
+        (reads, writes)
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn side_map_entries<T: Config>() -> u64 {
+        (v2::Expiries::<T>::iter_keys().count() +
+            v2::PendingTransfers::<T>::iter_keys().count() +
+            v2::CoOwners::<T>::iter_keys().count() +
+            v2::Approvals::<T>::iter_keys().count() +
+            v2::Metadata::<T>::iter_keys().count() +
+            v2::ClaimHistory::<T>::iter_keys().count() +
+            v2::Tombstones::<T>::iter_keys().count() +
+            v2::ClaimOperators::<T>::iter_keys().count() +
+            v2::Challenges::<T>::iter_keys().count()) as u64
+    }
+
+    /// Moves every per-claim storage entry into [`DEFAULT_NAMESPACE`] and clears the old
+    /// `HashedClaims` index. Runs unconditionally; use [`MigrateV2ToV3`] instead, which only
+    /// runs it on storage version 2.
+    pub struct VersionUncheckedMigrateV2ToV3<T>(PhantomData<T>);
+
+    impl<T: Config> OnRuntimeUpgrade for VersionUncheckedMigrateV2ToV3<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let mut moved = 0u64;
+            moved += move_into_default_namespace::<T, _, v2::Proofs<T>, Proofs<T>>();
+            moved += move_into_default_namespace::<T, _, v2::Deposits<T>, Deposits<T>>();
+            moved += move_into_default_namespace::<T, _, v2::Expiries<T>, Expiries<T>>();
+            moved +=
+                move_into_default_namespace::<T, _, v2::PendingTransfers<T>, PendingTransfers<T>>();
+            moved += move_into_default_namespace::<T, _, v2::CoOwners<T>, CoOwners<T>>();
+            moved += move_into_default_namespace::<T, _, v2::Metadata<T>, Metadata<T>>();
+            moved += move_into_default_namespace::<T, _, v2::ClaimHistory<T>, ClaimHistory<T>>();
+            moved += move_into_default_namespace::<T, _, v2::Tombstones<T>, Tombstones<T>>();
+            moved +=
+                move_into_default_namespace::<T, _, v2::ClaimOperators<T>, ClaimOperators<T>>();
+            moved += move_into_default_namespace::<T, _, v2::Challenges<T>, Challenges<T>>();
+
+            // 旧索引不含存证本身，清空后该存储项改为存放内容存证
+            let cleared = v2::HashedClaims::<T>::clear(u32::MAX, None).unique as u64;
+            moved += cleared;
+
+            // 批准按操作分别存储，旧版本每个存证只有一项待批准的操作
+            let approvals: Vec<_> = v2::Approvals::<T>::drain().collect();
+            moved += approvals.len() as u64;
+            for (claim, approval) in approvals {
+                let action_hash = T::Hashing::hash_of(&approval.action);
+                Approvals::<T>::insert((DEFAULT_NAMESPACE, claim, action_hash), approval);
+            }
+
+            // 队列和所有者索引的第二个键变为 (命名空间, 声明)
+            let expiries: Vec<_> = v2::ExpiryQueue::<T>::drain().collect();
+            let challenges: Vec<_> = v2::ChallengeQueue::<T>::drain().collect();
+            moved += (expiries.len() + challenges.len()) as u64;
+            for (block, claim, ()) in expiries {
+                ExpiryQueue::<T>::insert(block, (DEFAULT_NAMESPACE, claim), ());
+            }
+            for (block, claim, ()) in challenges {
+                ChallengeQueue::<T>::insert(block, (DEFAULT_NAMESPACE, claim), ());
+            }
+
+            // 为已有的要约建立过期队列，过期的要约随后由 on_idle 清除
+            let mut offers = 0u64;
+            for (claim, offer) in PendingTransfers::<T>::iter_prefix(DEFAULT_NAMESPACE) {
+                offers += 1;
+                OfferQueue::<T>::insert(offer.expires_at, (DEFAULT_NAMESPACE, &claim), ());
+                NextOfferCheck::<T>::mutate(|cursor| {
+                    *cursor =
+                        Some(cursor.map_or(offer.expires_at, |cursor| cursor.min(offer.expires_at)))
+                });
+            }
+            moved += offers;
+
+            let (reads, writes) = rebuild_indexes::<T>();
+
+            T::DbWeight::get().reads_writes(
+                moved.saturating_add(reads),
+                moved.saturating_mul(2).saturating_add(writes),
+            )
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+            let counts = Counts {
+                proofs: v2::Proofs::<T>::iter_keys().count() as u64,
+                deposits: v2::Deposits::<T>::iter_keys().count() as u64,
+                side_maps: side_map_entries::<T>(),
+                queued: (v2::ExpiryQueue::<T>::iter_keys().count() +
+                    v2::ChallengeQueue::<T>::iter_keys().count()) as u64,
+            };
+
+            Ok(counts.encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+            let counts: Counts =
+                Decode::decode(&mut &state[..]).map_err(|_| "invalid pre_upgrade state")?;
+            ensure!(
+                Proofs::<T>::iter_prefix_values(DEFAULT_NAMESPACE).count() as u64 == counts.proofs,
+                "Proofs entries were lost in the migration"
+            );
+            ensure!(
+                Deposits::<T>::iter_prefix_values(DEFAULT_NAMESPACE).count() as u64 >=
+                    counts.deposits,
+                "Deposits entries were lost in the migration"
+            );
+            ensure!(ClaimCount::<T>::get() == counts.proofs, "ClaimCount was not rebuilt");
+            let side_maps = (Expiries::<T>::iter_prefix_values(DEFAULT_NAMESPACE).count() +
+                PendingTransfers::<T>::iter_prefix_values(DEFAULT_NAMESPACE).count() +
+                CoOwners::<T>::iter_prefix_values(DEFAULT_NAMESPACE).count() +
+                Approvals::<T>::iter_keys().count() +
+                Metadata::<T>::iter_prefix_values(DEFAULT_NAMESPACE).count() +
+                ClaimHistory::<T>::iter_prefix_values(DEFAULT_NAMESPACE).count() +
+                Tombstones::<T>::iter_prefix_values(DEFAULT_NAMESPACE).count() +
+                ClaimOperators::<T>::iter_prefix_values(DEFAULT_NAMESPACE).count() +
+                Challenges::<T>::iter_prefix_values(DEFAULT_NAMESPACE).count()) as u64;
+            ensure!(side_maps == counts.side_maps, "per-claim entries were lost in the migration");
+
+            // 每个有效存证都在所有者索引中，并有押金记录
+            let mut active = 0usize;
+            for (namespace, claim, info) in Proofs::<T>::iter() {
+                if !info.status.is_active() {
+                    continue;
+                }
+                active += 1;
+                ensure!(
+                    ClaimsByOwner::<T>::contains_key(&info.owner, (namespace, &claim)),
+                    "an active claim is missing from the owner index"
+                );
+                ensure!(
+                    Deposits::<T>::contains_key(namespace, &claim),
+                    "an active claim has no deposit"
+                );
+            }
+            ensure!(
+                ClaimsByOwner::<T>::iter_keys().count() == active,
+                "the owner index lists claims that are not active"
+            );
+            for (owner, count) in OwnerClaimCount::<T>::iter() {
+                ensure!(
+                    count as usize == ClaimsByOwner::<T>::iter_prefix(&owner).count(),
+                    "OwnerClaimCount does not match the owner index"
+                );
+            }
+            ensure!(
+                (ExpiryQueue::<T>::iter_keys().count() + ChallengeQueue::<T>::iter_keys().count())
+                    as u64 == counts.queued,
+                "queued claims were lost in the migration"
+            );
+            ensure!(
+                v2::HashedClaims::<T>::iter_keys().next().is_none(),
+                "HashedClaims was not cleared"
+            );
+            ensure!(
+                OfferQueue::<T>::iter_keys().count() ==
+                    PendingTransfers::<T>::iter_keys().count(),
+                "a transfer offer is missing from the offer queue"
+            );
+
+            Ok(())
+        }
+    }
+
+    /// Migrates every per-claim storage item from storage version 2 to 3.
+    pub type MigrateV2ToV3<T> = VersionedMigration<
+        2,
+        3,
+        VersionUncheckedMigrateV2ToV3<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
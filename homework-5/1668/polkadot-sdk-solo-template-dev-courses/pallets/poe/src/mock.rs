@@ -1,10 +1,13 @@
 use crate as pallet_poe;
 use frame_support::{
     derive_impl,
-    traits::{ConstU16, ConstU64,ConstU32},
+    parameter_types,
+    traits::{ConstU16, ConstU64,ConstU32, UnixTime},
 };
+use frame_system::EnsureRoot;
 use sp_core::H256;
 use sp_runtime::{
+    testing::{TestSignature, UintAuthorityId},
     traits::{BlakeTwo256, IdentityLookup},
     BuildStorage,
 };
@@ -16,6 +19,7 @@ frame_support::construct_runtime!(
     pub enum Test
     {
         System: frame_system,
+        Balances: pallet_balances,
         PoeModule: pallet_poe,
     }
 );
@@ -38,7 +42,7 @@ impl frame_system::Config for Test {
     type BlockHashCount = ConstU64<250>;
     type Version = ();
     type PalletInfo = PalletInfo;
-    type AccountData = ();
+    type AccountData = pallet_balances::AccountData<u64>;
     type OnNewAccount = ();
     type OnKilledAccount = ();
     type SystemWeightInfo = ();
@@ -47,16 +51,115 @@ impl frame_system::Config for Test {
     type MaxConsumers = frame_support::traits::ConstU32<16>;
 }
 
+impl pallet_balances::Config for Test {
+    type MaxLocks = ConstU32<50>;
+    type MaxReserves = ();
+    type ReserveIdentifier = [u8; 8];
+    type Balance = u64;
+    type RuntimeEvent = RuntimeEvent;
+    type DustRemoval = ();
+    type ExistentialDeposit = ConstU64<1>;
+    type AccountStore = System;
+    type WeightInfo = ();
+    type FreezeIdentifier = ();
+    type MaxFreezes = ();
+    type RuntimeHoldReason = RuntimeHoldReason;
+    type RuntimeFreezeReason = ();
+}
+
+parameter_types! {
+    pub static AllowDirectTransfer: bool = true;
+    pub static Reregistration: pallet_poe::ReregistrationPolicy =
+        pallet_poe::ReregistrationPolicy::PreviousOwner;
+    // 当前时间（毫秒）
+    pub static Now: u64 = 1_700_000_000_000;
+}
+
+pub struct MockTime;
+impl UnixTime for MockTime {
+    fn now() -> core::time::Duration {
+        core::time::Duration::from_millis(Now::get())
+    }
+}
+
 impl pallet_poe::Config for Test {
     type MaxClaimLength = ConstU32<100>;    
     type RuntimeEvent = RuntimeEvent;
-    // type WeightInfo = ();
+    type RuntimeHoldReason = RuntimeHoldReason;
+    type UnixTime = MockTime;
+    type Currency = Balances;
+    type ForceOrigin = EnsureRoot<u64>;
+    type ArbiterOrigin = EnsureRoot<u64>;
+    type ChallengeBond = ConstU64<20>;
+    type ChallengePeriod = ConstU64<5>;
+    type ClaimDeposit = ConstU64<10>;
+    type DepositPerByte = ConstU64<1>;
+    type MaxClaimsPerOwner = ConstU32<3>;
+    type OfferTimeout = ConstU64<10>;
+    type AllowDirectTransfer = AllowDirectTransfer;
+    type Reregistration = Reregistration;
+    type MaxContentLength = ConstU32<1024>;
+    // 不超过限流窗口的名额，否则满批次的调用永远无法通过限流
+    type MaxBatchSize = ConstU32<3>;
+    type MaxHistoryLength = ConstU32<3>;
+    type MaxCoOwners = ConstU32<3>;
+    type MaxContentTypeLength = ConstU32<32>;
+    type MaxUriLength = ConstU32<64>;
+    type MaxDescriptionLength = ConstU32<64>;
+    type WeightInfo = ();
+    type OffchainSignature = TestSignature;
+    type OffchainPublic = UintAuthorityId;
+    type MaxClaimsPerAccountPerWindow = ConstU32<3>;
+    type WindowLength = ConstU64<10>;
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = MockBenchmarkHelper;
+}
+
+// 测试签名只记录签名人和消息
+#[cfg(feature = "runtime-benchmarks")]
+pub struct MockBenchmarkHelper;
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_poe::BenchmarkHelper<UintAuthorityId, TestSignature> for MockBenchmarkHelper {
+    fn create_public() -> UintAuthorityId {
+        UintAuthorityId(100)
+    }
+
+    fn sign(public: &UintAuthorityId, message: &[u8]) -> TestSignature {
+        TestSignature(public.0, message.to_vec())
+    }
 }
 
 // Build genesis storage according to the mock runtime.
-pub fn new_test_ext() -> sp_io::TestExternalities {
-    frame_system::GenesisConfig::<Test>::default()
+fn test_storage() -> sp_runtime::Storage {
+    let mut t = frame_system::GenesisConfig::<Test>::default()
         .build_storage()
-        .unwrap()
-        .into()
+        .unwrap();
+    // 账户 3 的余额不足以支付押金
+    pallet_balances::GenesisConfig::<Test> {
+        balances: vec![(1, 100), (2, 100), (3, 5)],
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
+    t
+}
+
+pub fn new_test_ext() -> sp_io::TestExternalities {
+    test_storage().into()
+}
+
+// 执行测试后检查存储不变量
+pub fn build_and_execute(test: impl FnOnce()) {
+    new_test_ext().execute_with(|| {
+        test();
+        PoeModule::do_try_state().unwrap();
+    });
+}
+
+// 在创世时预置存证
+pub fn new_test_ext_with_claims(claims: Vec<(Vec<u8>, u64)>) -> sp_io::TestExternalities {
+    let mut t = test_storage();
+    pallet_poe::GenesisConfig::<Test> { claims }
+        .assimilate_storage(&mut t)
+        .unwrap();
+    t.into()
 }
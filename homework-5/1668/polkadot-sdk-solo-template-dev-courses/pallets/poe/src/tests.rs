@@ -1,15 +1,40 @@
 use crate as pallet_poe;
-use crate::{mock::*, Error, Event};
-use frame_support::{assert_err, assert_ok};
-use sp_runtime::BoundedVec;
+use crate::{
+    mock::*, BatchMode, ClaimInfo, ClaimStatus, Error, Event, HashedClaim, HoldReason, JointAction,
+    NamespacePolicy, ProvenanceAction, ProvenanceRecord, ReregistrationPolicy, RevocationReason,
+    Tombstone, Verdict, WeightInfo,
+    CheckClaimRateLimit, DEFAULT_NAMESPACE, RATE_LIMITED,
+};
+use frame_support::{
+    assert_err, assert_noop, assert_ok,
+    dispatch::{DispatchInfo, WithPostDispatchInfo},
+    traits::{fungible::{Inspect, InspectHold}, ConstU32, Hooks},
+    weights::Weight,
+};
+use sp_runtime::{
+    testing::TestSignature,
+    traits::{BlakeTwo256, Hash, SignedExtension},
+    transaction_validity::{InvalidTransaction, ValidTransaction},
+    BoundedVec, DispatchError,
+};
 
 #[test]
 fn it_works_for_default_value() {
-    new_test_ext().execute_with(|| {
+    build_and_execute(|| {
         System::set_block_number(1);
         let claim = BoundedVec::try_from(vec![0, 1]).unwrap();
-        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone()));
-        assert_eq!(pallet_poe::Proofs::<Test>::get(&claim), Some((1_u64, 1_u64,true)));
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone(), None, None));
+        assert_eq!(
+            pallet_poe::Proofs::<Test>::get(DEFAULT_NAMESPACE, &claim),
+            Some(ClaimInfo {
+                owner: 1,
+                created_at: 1,
+                updated_at: 1,
+                created_time: Now::get(),
+                updated_time: Now::get(),
+                status: ClaimStatus::Active,
+            })
+        );
         // Go past genesis block so events get deposited
         println!("{:?}", System::events());
         System::set_block_number(1);
@@ -19,83 +44,91 @@ fn it_works_for_default_value() {
 
 #[test]
 fn create_claim_works() {
-    new_test_ext().execute_with(|| {
+    build_and_execute(|| {
         System::set_block_number(1);
         let claim  = BoundedVec::try_from(b"example_claim".to_vec()).unwrap();
         let sender = 1;
 
         // 创建声明
-        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone()));
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone(), None, None));
 
         // 检查存储内容
-        let (owner, _block_number, is_active) = pallet_poe::Proofs::<Test>::get(&claim).unwrap();
-        println!("Inserted proof: {:?}", pallet_poe::Proofs::<Test>::get(&claim));  // 打印存储内容
-        println!("Owner: {:?}, is_active: {}", owner, is_active); // 打印所有者和激活状态
+        let ClaimInfo { owner, status, .. } = pallet_poe::Proofs::<Test>::get(DEFAULT_NAMESPACE, &claim).unwrap();
+        println!("Inserted proof: {:?}", pallet_poe::Proofs::<Test>::get(DEFAULT_NAMESPACE, &claim));  // 打印存储内容
+        println!("Owner: {:?}, status: {:?}", owner, status); // 打印所有者和激活状态
         assert_eq!(owner, sender);
-        assert_eq!(is_active,true);
+        assert_eq!(status, ClaimStatus::Active);
 
         // 检查事件触发
         println!("{:?}", System::events());
-        System::assert_last_event(Event::ClaimCreated { owner: sender, claim }.into());
+        System::assert_last_event(Event::ClaimCreated { owner: sender, claim, metadata: None, timestamp: Now::get() }.into());
     });
 }
 
 #[test]
 fn revoke_claim_works() {
-    new_test_ext().execute_with(|| {
+    build_and_execute(|| {
         System::set_block_number(1);
         let claim= BoundedVec::try_from(b"example_claim".to_vec()).unwrap();
         let sender = 1;
 
         // 创建声明
-        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(sender), claim.clone()));
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(sender), claim.clone(), None, None));
 
         // 撤销声明
-        assert_ok!(PoeModule::revoke_claim(RuntimeOrigin::signed(sender), claim.clone()));
+        assert_ok!(PoeModule::revoke_claim(
+            RuntimeOrigin::signed(sender),
+            claim.clone(),
+            RevocationReason::Withdrawn
+        ));
 
         // 检查存储内容
-        let (owner, _block_number, is_active) = pallet_poe::Proofs::<Test>::get(&claim).unwrap();
+        let ClaimInfo { owner, status, .. } = pallet_poe::Proofs::<Test>::get(DEFAULT_NAMESPACE, &claim).unwrap();
         assert_eq!(owner, sender);
-        assert!(!is_active);
+        assert!(!status.is_active());
 
         // 检查事件触发
-        System::assert_last_event(Event::ClaimRevoked { owner: sender, claim }.into());
+        System::assert_last_event(
+            Event::ClaimRevoked { owner: sender, claim, reason: RevocationReason::Withdrawn }.into(),
+        );
     });
 }
 
 #[test]
 fn transfer_claim_works() {
-    new_test_ext().execute_with(|| {
+    build_and_execute(|| {
         System::set_block_number(1);
         let claim = BoundedVec::try_from(b"example_claim".to_vec()).unwrap();
         let sender = 1;
         let new_owner = 2;
 
         // 创建声明
-        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(sender), claim.clone()));
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(sender), claim.clone(), None, None));
 
         // 转移所有权
         assert_ok!(PoeModule::transfer_claim(RuntimeOrigin::signed(sender), claim.clone(), new_owner));
 
         // 检查存储内容
-        let (owner, _block_number, is_active) = pallet_poe::Proofs::<Test>::get(&claim).unwrap();
+        let ClaimInfo { owner, status, .. } = pallet_poe::Proofs::<Test>::get(DEFAULT_NAMESPACE, &claim).unwrap();
         assert_eq!(owner, new_owner);
-        assert!(is_active);
+        assert!(status.is_active());
 
         // 检查事件触发
-        System::assert_last_event(Event::ClaimTransferred { old_owner: sender, new_owner, claim }.into());
+        System::assert_last_event(
+            Event::ClaimTransferred { old_owner: sender, new_owner, claim, timestamp: Now::get() }.into(),
+        );
     });
 }
 
 #[test]
 fn cannot_revoke_nonexistent_claim() {
-    new_test_ext().execute_with(|| {
+    build_and_execute(|| {
         let claim = BoundedVec::try_from(b"nonexistent_claim".to_vec()).unwrap();
         let sender = 1;
 
         // 尝试撤销不存在的声明
         assert_err!(
-            PoeModule::revoke_claim(RuntimeOrigin::signed(sender), claim.clone()),
+            PoeModule::revoke_claim(RuntimeOrigin::signed(sender), claim.clone(), RevocationReason::Withdrawn),
             Error::<Test>::ProofNotExist
         );
     });
@@ -103,13 +136,13 @@ fn cannot_revoke_nonexistent_claim() {
 
 #[test]
 fn cannot_transfer_without_ownership() {
-    new_test_ext().execute_with(|| {
+    build_and_execute(|| {
         let claim = BoundedVec::try_from(b"example_claim".to_vec()).unwrap();
         let sender = 1;
         let new_owner = 2;
 
         // 创建声明
-        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(sender), claim.clone()));
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(sender), claim.clone(), None, None));
 
         // 尝试非所有者转移
         assert_err!(
@@ -119,3 +152,2034 @@ fn cannot_transfer_without_ownership() {
     });
 }
 
+#[test]
+fn calls_report_weight_of_actual_claim_length() {
+    build_and_execute(|| {
+        let claim: BoundedVec<u8, ConstU32<100>> = BoundedVec::try_from(vec![0; 5]).unwrap();
+        let post_info = PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone(), None, None).unwrap();
+        assert_eq!(
+            post_info.actual_weight,
+            Some(<() as WeightInfo>::create_claim(5).saturating_add(<() as WeightInfo>::check_rate_limit()))
+        );
+
+        let post_info = PoeModule::offer_claim(RuntimeOrigin::signed(1), claim.clone(), 2).unwrap();
+        assert_eq!(post_info.actual_weight, Some(<() as WeightInfo>::offer_claim(5)));
+        let post_info = PoeModule::accept_claim(RuntimeOrigin::signed(2), claim).unwrap();
+        assert_eq!(post_info.actual_weight, Some(<() as WeightInfo>::accept_claim(5)));
+    });
+}
+
+#[test]
+fn create_claim_holds_deposit() {
+    build_and_execute(|| {
+        let claim = BoundedVec::try_from(b"example_claim".to_vec()).unwrap();
+
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone(), None, None));
+
+        // 押金 = 基础押金 10 + 每字节 1 * 13
+        assert_eq!(PoeModule::deposit_for(DEFAULT_NAMESPACE, &claim), 23);
+        assert_eq!(Balances::balance_on_hold(&HoldReason::ClaimDeposit.into(), &1), 23);
+        assert_eq!(pallet_poe::Deposits::<Test>::get(DEFAULT_NAMESPACE, &claim), Some(23));
+    });
+}
+
+#[test]
+fn create_claim_fails_without_deposit() {
+    build_and_execute(|| {
+        let claim = BoundedVec::try_from(b"example_claim".to_vec()).unwrap();
+
+        assert_noop!(
+            PoeModule::create_claim(RuntimeOrigin::signed(3), claim.clone(), None, None),
+            Error::<Test>::InsufficientDeposit
+        );
+    });
+}
+
+#[test]
+fn revoke_claim_releases_deposit() {
+    build_and_execute(|| {
+        let claim = BoundedVec::try_from(b"example_claim".to_vec()).unwrap();
+
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone(), None, None));
+        assert_ok!(PoeModule::revoke_claim(
+            RuntimeOrigin::signed(1),
+            claim.clone(),
+            RevocationReason::Withdrawn
+        ));
+
+        assert_eq!(Balances::balance_on_hold(&HoldReason::ClaimDeposit.into(), &1), 0);
+        assert_eq!(Balances::free_balance(1), 100);
+        assert_eq!(pallet_poe::Deposits::<Test>::get(DEFAULT_NAMESPACE, &claim), None);
+    });
+}
+
+#[test]
+fn transfer_claim_moves_deposit() {
+    build_and_execute(|| {
+        let claim = BoundedVec::try_from(b"example_claim".to_vec()).unwrap();
+
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone(), None, None));
+        assert_ok!(PoeModule::transfer_claim(RuntimeOrigin::signed(1), claim.clone(), 2));
+
+        assert_eq!(Balances::balance_on_hold(&HoldReason::ClaimDeposit.into(), &1), 0);
+        assert_eq!(Balances::balance_on_hold(&HoldReason::ClaimDeposit.into(), &2), 23);
+    });
+}
+
+#[test]
+fn transfer_claim_fails_if_new_owner_cannot_afford_deposit() {
+    build_and_execute(|| {
+        let claim = BoundedVec::try_from(b"example_claim".to_vec()).unwrap();
+
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone(), None, None));
+        assert_noop!(
+            PoeModule::transfer_claim(RuntimeOrigin::signed(1), claim.clone(), 3),
+            Error::<Test>::InsufficientDeposit
+        );
+        assert_eq!(Balances::balance_on_hold(&HoldReason::ClaimDeposit.into(), &1), 23);
+    });
+}
+
+#[test]
+fn expired_claim_is_purged_on_idle() {
+    build_and_execute(|| {
+        System::set_block_number(1);
+        let claim = BoundedVec::try_from(b"example_claim".to_vec()).unwrap();
+
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone(), Some(10), None));
+        assert_eq!(pallet_poe::Expiries::<Test>::get(DEFAULT_NAMESPACE, &claim), Some(11));
+
+        // 过期前不会被清理
+        PoeModule::on_idle(10, Weight::MAX);
+        assert!(pallet_poe::Proofs::<Test>::contains_key(DEFAULT_NAMESPACE, &claim));
+
+        System::set_block_number(11);
+        assert!(PoeModule::is_expired(DEFAULT_NAMESPACE, &claim));
+        PoeModule::on_idle(11, Weight::MAX);
+
+        assert_eq!(pallet_poe::Proofs::<Test>::get(DEFAULT_NAMESPACE, &claim), None);
+        assert_eq!(pallet_poe::Expiries::<Test>::get(DEFAULT_NAMESPACE, &claim), None);
+        assert_eq!(pallet_poe::ExpiryQueue::<Test>::get(11, (DEFAULT_NAMESPACE, &claim)), None);
+        assert_eq!(Balances::balance_on_hold(&HoldReason::ClaimDeposit.into(), &1), 0);
+        System::assert_last_event(Event::ClaimExpired { owner: 1, claim: claim.clone() }.into());
+
+        // 过期清理后历史记录仍然保留
+        let history = PoeModule::claim_history(DEFAULT_NAMESPACE, claim.to_vec());
+        assert_eq!(
+            history.iter().map(|record| record.action).collect::<Vec<_>>(),
+            vec![ProvenanceAction::Created, ProvenanceAction::Expired]
+        );
+        assert_eq!(history[1].from, Some(1));
+        assert_eq!(history[1].to, None);
+    });
+}
+
+#[test]
+fn create_claim_rejects_zero_lifetime() {
+    build_and_execute(|| {
+        let claim = BoundedVec::try_from(b"example_claim".to_vec()).unwrap();
+
+        assert_noop!(
+            PoeModule::create_claim(RuntimeOrigin::signed(1), claim, Some(0), None),
+            Error::<Test>::InvalidLifetime
+        );
+    });
+}
+
+#[test]
+fn renew_claim_works() {
+    build_and_execute(|| {
+        System::set_block_number(1);
+        let claim = BoundedVec::try_from(b"example_claim".to_vec()).unwrap();
+
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone(), Some(10), None));
+        assert_ok!(PoeModule::renew_claim(RuntimeOrigin::signed(1), claim.clone(), 5));
+
+        assert_eq!(pallet_poe::Expiries::<Test>::get(DEFAULT_NAMESPACE, &claim), Some(16));
+        assert_eq!(pallet_poe::ExpiryQueue::<Test>::get(11, (DEFAULT_NAMESPACE, &claim)), None);
+        assert_eq!(pallet_poe::ExpiryQueue::<Test>::get(16, (DEFAULT_NAMESPACE, &claim)), Some(()));
+        System::assert_last_event(
+            Event::ClaimRenewed { owner: 1, claim: claim.clone(), expires_at: 16 }.into(),
+        );
+
+        // 续期后在原过期区块不会被清理
+        System::set_block_number(11);
+        PoeModule::on_idle(11, Weight::MAX);
+        assert!(pallet_poe::Proofs::<Test>::contains_key(DEFAULT_NAMESPACE, &claim));
+    });
+}
+
+#[test]
+fn renew_claim_fails_for_permanent_or_expired_claim() {
+    build_and_execute(|| {
+        System::set_block_number(1);
+        let permanent = BoundedVec::try_from(b"permanent".to_vec()).unwrap();
+        let expiring = BoundedVec::try_from(b"expiring".to_vec()).unwrap();
+
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), permanent.clone(), None, None));
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), expiring.clone(), Some(5), None));
+
+        assert_noop!(
+            PoeModule::renew_claim(RuntimeOrigin::signed(1), permanent, 5),
+            Error::<Test>::ProofNotExpiring
+        );
+        assert_noop!(
+            PoeModule::renew_claim(RuntimeOrigin::signed(2), expiring.clone(), 5),
+            Error::<Test>::NotProofOwner
+        );
+
+        System::set_block_number(6);
+        assert_noop!(
+            PoeModule::renew_claim(RuntimeOrigin::signed(1), expiring.clone(), 5),
+            Error::<Test>::ProofExpired
+        );
+        assert_noop!(
+            PoeModule::transfer_claim(RuntimeOrigin::signed(1), expiring, 2),
+            Error::<Test>::ProofExpired
+        );
+    });
+}
+
+#[test]
+fn owner_index_follows_claim_lifecycle() {
+    build_and_execute(|| {
+        System::set_block_number(1);
+        let claim = BoundedVec::try_from(b"example_claim".to_vec()).unwrap();
+        let expiring = BoundedVec::try_from(b"expiring".to_vec()).unwrap();
+
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone(), None, None));
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), expiring.clone(), Some(5), None));
+        assert_eq!(pallet_poe::OwnerClaimCount::<Test>::get(1), 2);
+        assert!(pallet_poe::ClaimsByOwner::<Test>::contains_key(1, (DEFAULT_NAMESPACE, &claim)));
+
+        // 转移后索引随之转移
+        assert_ok!(PoeModule::transfer_claim(RuntimeOrigin::signed(1), claim.clone(), 2));
+        assert!(!pallet_poe::ClaimsByOwner::<Test>::contains_key(1, (DEFAULT_NAMESPACE, &claim)));
+        assert!(pallet_poe::ClaimsByOwner::<Test>::contains_key(2, (DEFAULT_NAMESPACE, &claim)));
+        assert_eq!(pallet_poe::OwnerClaimCount::<Test>::get(1), 1);
+        assert_eq!(pallet_poe::OwnerClaimCount::<Test>::get(2), 1);
+
+        // 撤销后从索引中移除
+        assert_ok!(PoeModule::revoke_claim(
+            RuntimeOrigin::signed(2),
+            claim.clone(),
+            RevocationReason::Withdrawn
+        ));
+        assert!(!pallet_poe::ClaimsByOwner::<Test>::contains_key(2, (DEFAULT_NAMESPACE, &claim)));
+        assert!(!pallet_poe::OwnerClaimCount::<Test>::contains_key(2));
+
+        // 过期清理后从索引中移除
+        System::set_block_number(6);
+        PoeModule::on_idle(6, Weight::MAX);
+        assert_eq!(pallet_poe::ClaimsByOwner::<Test>::iter_prefix(1).count(), 0);
+        assert!(!pallet_poe::OwnerClaimCount::<Test>::contains_key(1));
+    });
+}
+
+#[test]
+fn create_claim_fails_when_owner_hits_cap() {
+    build_and_execute(|| {
+        for i in 0..3u8 {
+            let claim = BoundedVec::try_from(vec![i]).unwrap();
+            assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), claim, None, None));
+        }
+
+        let claim = BoundedVec::try_from(vec![3]).unwrap();
+        assert_noop!(
+            PoeModule::create_claim(RuntimeOrigin::signed(1), claim, None, None),
+            Error::<Test>::TooManyClaims
+        );
+    });
+}
+
+#[test]
+fn transfer_claim_fails_when_new_owner_hits_cap() {
+    build_and_execute(|| {
+        for i in 0..3u8 {
+            let claim = BoundedVec::try_from(vec![i]).unwrap();
+            assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(2), claim, None, None));
+        }
+
+        let claim = BoundedVec::try_from(vec![3]).unwrap();
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone(), None, None));
+        assert_noop!(
+            PoeModule::transfer_claim(RuntimeOrigin::signed(1), claim, 2),
+            Error::<Test>::TooManyClaims
+        );
+    });
+}
+
+#[test]
+fn claim_details_reports_claim_state() {
+    build_and_execute(|| {
+        System::set_block_number(1);
+        let claim = BoundedVec::try_from(b"example_claim".to_vec()).unwrap();
+
+        assert_eq!(PoeModule::claim_details(DEFAULT_NAMESPACE, b"example_claim".to_vec()), None);
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone(), Some(10), None));
+        assert_eq!(
+            PoeModule::claim_details(DEFAULT_NAMESPACE, b"example_claim".to_vec()),
+            Some(pallet_poe::ClaimDetails {
+                owner: 1,
+                created_at: 1,
+                updated_at: 1,
+                created_time: Now::get(),
+                updated_time: Now::get(),
+                is_active: true,
+                expires_at: Some(11),
+                locked_until: None,
+            })
+        );
+        assert_eq!(pallet_poe::ClaimCount::<Test>::get(), 1);
+
+        // 已过期但尚未清理的存证视为无效
+        System::set_block_number(11);
+        assert!(!PoeModule::claim_details(DEFAULT_NAMESPACE, b"example_claim".to_vec()).unwrap().is_active);
+
+        PoeModule::on_idle(11, Weight::MAX);
+        assert_eq!(PoeModule::claim_details(DEFAULT_NAMESPACE, b"example_claim".to_vec()), None);
+        assert_eq!(pallet_poe::ClaimCount::<Test>::get(), 0);
+    });
+}
+
+#[test]
+fn claims_of_paginates_with_cursor() {
+    build_and_execute(|| {
+        for i in 0..3u8 {
+            let claim = BoundedVec::try_from(vec![i]).unwrap();
+            assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), claim, None, None));
+        }
+
+        let first_page = PoeModule::claims_of(1, None, 2);
+        assert_eq!(first_page.len(), 2);
+
+        let second_page = PoeModule::claims_of(1, first_page.last().cloned(), 2);
+        assert_eq!(second_page.len(), 1);
+
+        let mut all: Vec<_> = first_page.into_iter().chain(second_page).collect();
+        all.sort();
+        assert_eq!(
+            all,
+            vec![(DEFAULT_NAMESPACE, vec![0]), (DEFAULT_NAMESPACE, vec![1]), (DEFAULT_NAMESPACE, vec![2])]
+        );
+        assert!(PoeModule::claims_of(2, None, 10).is_empty());
+    });
+}
+
+#[test]
+fn offer_and_accept_claim_works() {
+    build_and_execute(|| {
+        System::set_block_number(1);
+        let claim = BoundedVec::try_from(b"example_claim".to_vec()).unwrap();
+
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone(), None, None));
+        assert_ok!(PoeModule::offer_claim(RuntimeOrigin::signed(1), claim.clone(), 2));
+        System::assert_last_event(
+            Event::ClaimOffered { owner: 1, to: 2, claim: claim.clone(), expires_at: 11 }.into(),
+        );
+
+        // 只有要约接收方可以接受
+        assert_noop!(
+            PoeModule::accept_claim(RuntimeOrigin::signed(3), claim.clone()),
+            Error::<Test>::NotOfferParty
+        );
+
+        assert_ok!(PoeModule::accept_claim(RuntimeOrigin::signed(2), claim.clone()));
+        let ClaimInfo { owner, .. } = pallet_poe::Proofs::<Test>::get(DEFAULT_NAMESPACE, &claim).unwrap();
+        assert_eq!(owner, 2);
+        assert_eq!(pallet_poe::PendingTransfers::<Test>::get(DEFAULT_NAMESPACE, &claim), None);
+        assert_eq!(Balances::balance_on_hold(&HoldReason::ClaimDeposit.into(), &2), 23);
+        System::assert_last_event(
+            Event::OfferAccepted { old_owner: 1, new_owner: 2, claim }.into(),
+        );
+    });
+}
+
+#[test]
+fn accepting_expired_offer_removes_it() {
+    build_and_execute(|| {
+        System::set_block_number(1);
+        let claim = BoundedVec::try_from(b"example_claim".to_vec()).unwrap();
+
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone(), None, None));
+        assert_ok!(PoeModule::offer_claim(RuntimeOrigin::signed(1), claim.clone(), 2));
+
+        System::set_block_number(11);
+        assert_ok!(PoeModule::accept_claim(RuntimeOrigin::signed(2), claim.clone()));
+        System::assert_last_event(Event::OfferExpired { to: 2, claim: claim.clone() }.into());
+        assert_eq!(pallet_poe::Proofs::<Test>::get(DEFAULT_NAMESPACE, &claim).unwrap().owner, 1);
+        assert_eq!(pallet_poe::PendingTransfers::<Test>::get(DEFAULT_NAMESPACE, &claim), None);
+        assert_eq!(pallet_poe::OfferQueue::<Test>::iter().count(), 0);
+        assert_noop!(
+            PoeModule::accept_claim(RuntimeOrigin::signed(2), claim),
+            Error::<Test>::OfferNotExist
+        );
+    });
+}
+
+#[test]
+fn expired_offer_can_be_cancelled_by_anyone() {
+    build_and_execute(|| {
+        System::set_block_number(1);
+        let claim = BoundedVec::try_from(b"example_claim".to_vec()).unwrap();
+
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone(), None, None));
+        assert_ok!(PoeModule::offer_claim(RuntimeOrigin::signed(1), claim.clone(), 2));
+
+        System::set_block_number(11);
+        assert_ok!(PoeModule::cancel_offer(RuntimeOrigin::signed(3), claim.clone()));
+        System::assert_last_event(Event::OfferExpired { to: 2, claim: claim.clone() }.into());
+        assert_eq!(pallet_poe::PendingTransfers::<Test>::get(DEFAULT_NAMESPACE, &claim), None);
+        assert_eq!(pallet_poe::OfferQueue::<Test>::iter().count(), 0);
+    });
+}
+
+#[test]
+fn expired_offers_are_removed_on_idle() {
+    build_and_execute(|| {
+        System::set_block_number(1);
+        let claim = BoundedVec::try_from(b"example_claim".to_vec()).unwrap();
+        let other = BoundedVec::try_from(b"other_claim".to_vec()).unwrap();
+
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone(), None, None));
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), other.clone(), None, None));
+        assert_ok!(PoeModule::offer_claim(RuntimeOrigin::signed(1), claim.clone(), 2));
+        System::set_block_number(3);
+        assert_ok!(PoeModule::offer_claim(RuntimeOrigin::signed(1), other.clone(), 2));
+
+        PoeModule::on_idle(10, Weight::MAX);
+        assert!(pallet_poe::PendingTransfers::<Test>::contains_key(DEFAULT_NAMESPACE, &claim));
+
+        PoeModule::on_idle(11, Weight::MAX);
+        assert!(!pallet_poe::PendingTransfers::<Test>::contains_key(DEFAULT_NAMESPACE, &claim));
+        assert!(pallet_poe::PendingTransfers::<Test>::contains_key(DEFAULT_NAMESPACE, &other));
+        System::assert_last_event(Event::OfferExpired { to: 2, claim }.into());
+        assert_eq!(pallet_poe::NextOfferCheck::<Test>::get(), Some(12));
+
+        // 被新要约替换的旧要约不会留在队列中
+        assert_ok!(PoeModule::offer_claim(RuntimeOrigin::signed(1), other.clone(), 3));
+        assert_eq!(pallet_poe::OfferQueue::<Test>::iter().count(), 1);
+    });
+}
+
+#[test]
+fn cancel_offer_works() {
+    build_and_execute(|| {
+        System::set_block_number(1);
+        let claim = BoundedVec::try_from(b"example_claim".to_vec()).unwrap();
+
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone(), None, None));
+        assert_ok!(PoeModule::offer_claim(RuntimeOrigin::signed(1), claim.clone(), 2));
+
+        assert_noop!(
+            PoeModule::cancel_offer(RuntimeOrigin::signed(3), claim.clone()),
+            Error::<Test>::NotOfferParty
+        );
+        assert_ok!(PoeModule::cancel_offer(RuntimeOrigin::signed(1), claim.clone()));
+        System::assert_last_event(Event::OfferCancelled { to: 2, claim: claim.clone() }.into());
+
+        assert_noop!(
+            PoeModule::accept_claim(RuntimeOrigin::signed(2), claim),
+            Error::<Test>::OfferNotExist
+        );
+    });
+}
+
+#[test]
+fn direct_transfer_can_be_disabled() {
+    build_and_execute(|| {
+        let claim = BoundedVec::try_from(b"example_claim".to_vec()).unwrap();
+
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone(), None, None));
+
+        AllowDirectTransfer::set(false);
+        assert_noop!(
+            PoeModule::transfer_claim(RuntimeOrigin::signed(1), claim, 2),
+            Error::<Test>::DirectTransferDisabled
+        );
+        AllowDirectTransfer::set(true);
+    });
+}
+
+#[test]
+fn jointly_owned_claim_requires_threshold_to_transfer() {
+    build_and_execute(|| {
+        let claim = BoundedVec::try_from(b"example_claim".to_vec()).unwrap();
+        let co_owners = BoundedVec::try_from(vec![1, 2, 3]).unwrap();
+
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone(), None, None));
+        assert_ok!(PoeModule::set_co_owners(RuntimeOrigin::signed(1), claim.clone(), co_owners, 2));
+
+        // 共有存证不能由所有者单方转移或撤销
+        assert_noop!(
+            PoeModule::transfer_claim(RuntimeOrigin::signed(1), claim.clone(), 2),
+            Error::<Test>::RequiresApproval
+        );
+        assert_noop!(
+            PoeModule::revoke_claim(RuntimeOrigin::signed(1), claim.clone(), RevocationReason::Withdrawn),
+            Error::<Test>::RequiresApproval
+        );
+
+        let action = JointAction::Transfer(2);
+        assert_ok!(PoeModule::approve_action(RuntimeOrigin::signed(1), claim.clone(), action.clone()));
+        assert_noop!(
+            PoeModule::approve_action(RuntimeOrigin::signed(1), claim.clone(), action.clone()),
+            Error::<Test>::AlreadyApproved
+        );
+        assert_noop!(
+            PoeModule::approve_action(RuntimeOrigin::signed(4), claim.clone(), action.clone()),
+            Error::<Test>::NotCoOwner
+        );
+        let ClaimInfo { owner, .. } = pallet_poe::Proofs::<Test>::get(DEFAULT_NAMESPACE, &claim).unwrap();
+        assert_eq!(owner, 1);
+
+        assert_ok!(PoeModule::approve_action(RuntimeOrigin::signed(3), claim.clone(), action.clone()));
+        let ClaimInfo { owner, .. } = pallet_poe::Proofs::<Test>::get(DEFAULT_NAMESPACE, &claim).unwrap();
+        assert_eq!(owner, 2);
+        assert_eq!(pallet_poe::CoOwners::<Test>::get(DEFAULT_NAMESPACE, &claim), None);
+        assert_eq!(pallet_poe::Approvals::<Test>::iter().count(), 0);
+        System::assert_last_event(Event::ActionExecuted { claim, action }.into());
+    });
+}
+
+#[test]
+fn jointly_owned_claim_can_be_revoked_by_approval() {
+    build_and_execute(|| {
+        let claim = BoundedVec::try_from(b"example_claim".to_vec()).unwrap();
+        let co_owners = BoundedVec::try_from(vec![1, 2]).unwrap();
+
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone(), None, None));
+        assert_ok!(PoeModule::set_co_owners(RuntimeOrigin::signed(1), claim.clone(), co_owners, 2));
+
+        // 不同的操作分别收集批准
+        assert_ok!(PoeModule::approve_action(
+            RuntimeOrigin::signed(1),
+            claim.clone(),
+            JointAction::Transfer(3)
+        ));
+        let revoke = JointAction::Revoke(RevocationReason::Withdrawn);
+        assert_ok!(PoeModule::approve_action(RuntimeOrigin::signed(2), claim.clone(), revoke.clone()));
+        assert_eq!(pallet_poe::Approvals::<Test>::iter().count(), 2);
+        assert_eq!(
+            pallet_poe::Approvals::<Test>::get((DEFAULT_NAMESPACE, &claim, BlakeTwo256::hash_of(&revoke)))
+                .unwrap()
+                .approvals
+                .into_inner(),
+            vec![2]
+        );
+
+        // 改为批准撤销时撤回对转移的批准
+        assert_ok!(PoeModule::approve_action(RuntimeOrigin::signed(1), claim.clone(), revoke));
+        let ClaimInfo { status, .. } = pallet_poe::Proofs::<Test>::get(DEFAULT_NAMESPACE, &claim).unwrap();
+        assert!(!status.is_active());
+        assert_eq!(Balances::balance_on_hold(&HoldReason::ClaimDeposit.into(), &1), 0);
+    });
+}
+
+#[test]
+fn co_owner_cannot_discard_approvals_of_another_action() {
+    build_and_execute(|| {
+        let claim = BoundedVec::try_from(b"example_claim".to_vec()).unwrap();
+        let co_owners = BoundedVec::try_from(vec![1, 2, 3]).unwrap();
+
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone(), None, None));
+        assert_ok!(PoeModule::set_co_owners(RuntimeOrigin::signed(1), claim.clone(), co_owners, 2));
+
+        let transfer = JointAction::Transfer(2);
+        assert_ok!(PoeModule::approve_action(RuntimeOrigin::signed(1), claim.clone(), transfer.clone()));
+
+        // 共有人 3 反复批准其他操作，不会影响共有人 1 已给出的批准
+        assert_ok!(PoeModule::approve_action(
+            RuntimeOrigin::signed(3),
+            claim.clone(),
+            JointAction::Revoke(RevocationReason::Withdrawn)
+        ));
+        assert_ok!(PoeModule::approve_action(RuntimeOrigin::signed(3), claim.clone(), JointAction::Transfer(4)));
+        assert_eq!(pallet_poe::Approvals::<Test>::iter().count(), 2);
+        assert_eq!(
+            pallet_poe::Approvals::<Test>::get((DEFAULT_NAMESPACE, &claim, BlakeTwo256::hash_of(&transfer)))
+                .unwrap()
+                .approvals
+                .into_inner(),
+            vec![1]
+        );
+
+        assert_ok!(PoeModule::approve_action(RuntimeOrigin::signed(2), claim.clone(), transfer.clone()));
+        assert_eq!(pallet_poe::Proofs::<Test>::get(DEFAULT_NAMESPACE, &claim).unwrap().owner, 2);
+        assert_eq!(pallet_poe::Approvals::<Test>::iter().count(), 0);
+        System::assert_last_event(Event::ActionExecuted { claim, action: transfer }.into());
+    });
+}
+
+#[test]
+fn set_co_owners_validates_input() {
+    build_and_execute(|| {
+        let claim = BoundedVec::try_from(b"example_claim".to_vec()).unwrap();
+
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone(), None, None));
+        assert_noop!(
+            PoeModule::set_co_owners(
+                RuntimeOrigin::signed(1),
+                claim.clone(),
+                BoundedVec::try_from(vec![2, 3]).unwrap(),
+                1
+            ),
+            Error::<Test>::InvalidCoOwners
+        );
+        assert_noop!(
+            PoeModule::set_co_owners(
+                RuntimeOrigin::signed(1),
+                claim.clone(),
+                BoundedVec::try_from(vec![1, 2, 2]).unwrap(),
+                1
+            ),
+            Error::<Test>::InvalidCoOwners
+        );
+        assert_noop!(
+            PoeModule::set_co_owners(
+                RuntimeOrigin::signed(1),
+                claim.clone(),
+                BoundedVec::try_from(vec![1, 2]).unwrap(),
+                3
+            ),
+            Error::<Test>::InvalidThreshold
+        );
+        assert_noop!(
+            PoeModule::set_co_owners(
+                RuntimeOrigin::signed(2),
+                claim,
+                BoundedVec::try_from(vec![1, 2]).unwrap(),
+                1
+            ),
+            Error::<Test>::NotProofOwner
+        );
+    });
+}
+
+fn example_metadata() -> pallet_poe::ClaimMetadata<Test> {
+    pallet_poe::ClaimMetadata {
+        content_type: BoundedVec::try_from(b"application/pdf".to_vec()).unwrap(),
+        uri: BoundedVec::try_from(b"ipfs://example".to_vec()).unwrap(),
+        description: BoundedVec::try_from(b"contract".to_vec()).unwrap(),
+    }
+}
+
+#[test]
+fn create_claim_with_metadata_works() {
+    build_and_execute(|| {
+        System::set_block_number(1);
+        let claim = BoundedVec::try_from(b"example_claim".to_vec()).unwrap();
+        let metadata = example_metadata();
+
+        assert_ok!(PoeModule::create_claim(
+            RuntimeOrigin::signed(1),
+            claim.clone(),
+            None,
+            Some(metadata.clone())
+        ));
+
+        assert_eq!(pallet_poe::Metadata::<Test>::get(DEFAULT_NAMESPACE, &claim), Some(metadata.clone()));
+        // 押金 = 10 + 13 + 元数据 37 字节
+        assert_eq!(Balances::balance_on_hold(&HoldReason::ClaimDeposit.into(), &1), 60);
+        System::assert_last_event(
+            Event::ClaimCreated { owner: 1, claim, metadata: Some(metadata), timestamp: Now::get() }.into(),
+        );
+    });
+}
+
+#[test]
+fn set_claim_metadata_works() {
+    build_and_execute(|| {
+        System::set_block_number(1);
+        let claim = BoundedVec::try_from(b"example_claim".to_vec()).unwrap();
+        let metadata = example_metadata();
+
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone(), None, None));
+        assert_noop!(
+            PoeModule::set_claim_metadata(RuntimeOrigin::signed(2), claim.clone(), metadata.clone()),
+            Error::<Test>::NotProofOwner
+        );
+
+        assert_ok!(PoeModule::set_claim_metadata(RuntimeOrigin::signed(1), claim.clone(), metadata.clone()));
+        assert_eq!(Balances::balance_on_hold(&HoldReason::ClaimDeposit.into(), &1), 60);
+        assert_eq!(pallet_poe::Deposits::<Test>::get(DEFAULT_NAMESPACE, &claim), Some(60));
+        System::assert_last_event(
+            Event::ClaimMetadataUpdated { owner: 1, claim: claim.clone(), metadata }.into(),
+        );
+
+        // 缩短元数据后退还多余押金
+        let shorter = pallet_poe::ClaimMetadata {
+            content_type: Default::default(),
+            uri: Default::default(),
+            description: BoundedVec::try_from(b"note".to_vec()).unwrap(),
+        };
+        assert_ok!(PoeModule::set_claim_metadata(RuntimeOrigin::signed(1), claim.clone(), shorter));
+        assert_eq!(Balances::balance_on_hold(&HoldReason::ClaimDeposit.into(), &1), 27);
+    });
+}
+
+#[test]
+fn create_claim_from_content_keys_by_hash() {
+    build_and_execute(|| {
+        System::set_block_number(1);
+        let content: BoundedVec<_, _> = BoundedVec::try_from(b"the full document".to_vec()).unwrap();
+        let hash = BlakeTwo256::hash(&content);
+
+        assert_ok!(PoeModule::create_claim_from_content(RuntimeOrigin::signed(1), content.clone()));
+        System::assert_last_event(Event::HashedClaimCreated { owner: 1, hash, timestamp: Now::get() }.into());
+        assert_eq!(
+            pallet_poe::HashedClaims::<Test>::get(hash),
+            Some(HashedClaim { owner: 1, created_at: 1, created_time: Now::get(), deposit: 42 })
+        );
+        // 押金 = 10 + 32 字节的哈希
+        assert_eq!(Balances::balance_on_hold(&HoldReason::ClaimDeposit.into(), &1), 42);
+        // 内容存证不占用按字节登记的存证
+        assert_eq!(pallet_poe::ClaimCount::<Test>::get(), 0);
+
+        // 同一内容不能重复登记
+        assert_noop!(
+            PoeModule::create_claim_from_content(RuntimeOrigin::signed(2), content),
+            Error::<Test>::ProofAlreadyExist
+        );
+    });
+}
+
+#[test]
+fn hashed_claim_can_be_transferred_and_revoked() {
+    build_and_execute(|| {
+        System::set_block_number(1);
+        let content: BoundedVec<_, _> = BoundedVec::try_from(b"the full document".to_vec()).unwrap();
+        let hash = BlakeTwo256::hash(&content);
+        assert_ok!(PoeModule::create_claim_from_content(RuntimeOrigin::signed(1), content.clone()));
+
+        assert_noop!(
+            PoeModule::transfer_hashed_claim(RuntimeOrigin::signed(2), hash, 2),
+            Error::<Test>::NotProofOwner
+        );
+        assert_noop!(
+            PoeModule::transfer_hashed_claim(RuntimeOrigin::signed(1), hash, 1),
+            Error::<Test>::CannotTransferToSelf
+        );
+        assert_noop!(
+            PoeModule::transfer_hashed_claim(RuntimeOrigin::signed(1), hash, 3),
+            Error::<Test>::InsufficientDeposit
+        );
+
+        // 押金随所有权转移
+        assert_ok!(PoeModule::transfer_hashed_claim(RuntimeOrigin::signed(1), hash, 2));
+        System::assert_last_event(
+            Event::HashedClaimTransferred { old_owner: 1, new_owner: 2, hash, timestamp: Now::get() }.into(),
+        );
+        assert_eq!(pallet_poe::HashedClaims::<Test>::get(hash).unwrap().owner, 2);
+        assert_eq!(Balances::balance_on_hold(&HoldReason::ClaimDeposit.into(), &1), 0);
+        assert_eq!(Balances::balance_on_hold(&HoldReason::ClaimDeposit.into(), &2), 42);
+
+        assert_noop!(
+            PoeModule::revoke_hashed_claim(RuntimeOrigin::signed(1), hash),
+            Error::<Test>::NotProofOwner
+        );
+        assert_ok!(PoeModule::revoke_hashed_claim(RuntimeOrigin::signed(2), hash));
+        System::assert_last_event(Event::HashedClaimRevoked { owner: 2, hash }.into());
+        assert!(!pallet_poe::HashedClaims::<Test>::contains_key(hash));
+        assert_eq!(Balances::balance_on_hold(&HoldReason::ClaimDeposit.into(), &2), 0);
+        assert_noop!(
+            PoeModule::revoke_hashed_claim(RuntimeOrigin::signed(2), hash),
+            Error::<Test>::ProofNotExist
+        );
+
+        // 撤销后同一内容可以重新登记
+        assert_ok!(PoeModule::create_claim_from_content(RuntimeOrigin::signed(1), content));
+    });
+}
+
+#[test]
+fn create_claim_from_content_indexes_content_offchain() {
+    let mut ext = new_test_ext();
+    let content = b"the full document".to_vec();
+    let hash = BlakeTwo256::hash(&content);
+    ext.execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(PoeModule::create_claim_from_content(
+            RuntimeOrigin::signed(1),
+            BoundedVec::try_from(content.clone()).unwrap()
+        ));
+    });
+
+    // 区块导入后链下索引的写入才会落盘
+    ext.persist_offchain_overlay();
+    assert_eq!(ext.offchain_db().get(&pallet_poe::offchain_content_key(&hash)), Some(content));
+}
+
+fn batch(items: &[&[u8]]) -> BoundedVec<BoundedVec<u8, ConstU32<100>>, ConstU32<3>> {
+    let items: Vec<_> = items.iter().map(|item| BoundedVec::try_from(item.to_vec()).unwrap()).collect();
+    BoundedVec::try_from(items).unwrap()
+}
+
+#[test]
+fn create_claims_best_effort_reports_failures() {
+    build_and_execute(|| {
+        System::set_block_number(1);
+        let existing = BoundedVec::try_from(b"b".to_vec()).unwrap();
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), existing, None, None));
+
+        let post_info = PoeModule::create_claims(
+            RuntimeOrigin::signed(1),
+            batch(&[b"a", b"b", b"c"]),
+            BatchMode::BestEffort,
+        )
+        .unwrap();
+
+        // 失败的项目按失败的成本计费
+        assert_eq!(
+            post_info.actual_weight,
+            Some(<() as WeightInfo>::create_claims(2, 1, 3).saturating_add(<() as WeightInfo>::check_rate_limit()))
+        );
+        assert_eq!(pallet_poe::OwnerClaimCount::<Test>::get(1), 3);
+        System::assert_last_event(
+            Event::BatchCompleted {
+                succeeded: 2,
+                failures: vec![(1, Error::<Test>::ProofAlreadyExist.into())],
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn create_claims_all_or_nothing_reverts_on_failure() {
+    build_and_execute(|| {
+        let existing = BoundedVec::try_from(b"b".to_vec()).unwrap();
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), existing, None, None));
+
+        // 只收取已处理项目的权重
+        assert_noop!(
+            PoeModule::create_claims(
+                RuntimeOrigin::signed(1),
+                batch(&[b"a", b"b"]),
+                BatchMode::AllOrNothing
+            ),
+            Error::<Test>::ProofAlreadyExist.with_weight(
+                <() as WeightInfo>::create_claims(1, 1, 2).saturating_add(<() as WeightInfo>::check_rate_limit())
+            )
+        );
+    });
+}
+
+#[test]
+fn revoke_and_transfer_claims_work() {
+    build_and_execute(|| {
+        System::set_block_number(1);
+        assert_ok!(PoeModule::create_claims(
+            RuntimeOrigin::signed(1),
+            batch(&[b"a", b"b", b"c"]),
+            BatchMode::AllOrNothing
+        ));
+
+        assert_ok!(PoeModule::transfer_claims(
+            RuntimeOrigin::signed(1),
+            batch(&[b"a", b"b"]),
+            2,
+            BatchMode::AllOrNothing
+        ));
+        assert_eq!(pallet_poe::OwnerClaimCount::<Test>::get(2), 2);
+
+        // 账户 1 已不再拥有 a
+        assert_ok!(PoeModule::revoke_claims(
+            RuntimeOrigin::signed(1),
+            batch(&[b"a", b"c"]),
+            RevocationReason::Superseded,
+            BatchMode::BestEffort
+        ));
+        System::assert_last_event(
+            Event::BatchCompleted {
+                succeeded: 1,
+                failures: vec![(0, Error::<Test>::NotProofOwner.into())],
+            }
+            .into(),
+        );
+        assert_eq!(Balances::balance_on_hold(&HoldReason::ClaimDeposit.into(), &1), 0);
+    });
+}
+
+fn hash_leaf(doc: sp_core::H256) -> sp_core::H256 {
+    BlakeTwo256::hash(&[&[0x00], doc.as_bytes()].concat())
+}
+
+fn hash_pair(a: sp_core::H256, b: sp_core::H256) -> sp_core::H256 {
+    let (left, right) = if a <= b { (a, b) } else { (b, a) };
+    BlakeTwo256::hash(&[&[0x01], left.as_bytes(), right.as_bytes()].concat())
+}
+
+#[test]
+fn anchored_root_verifies_inclusion() {
+    build_and_execute(|| {
+        System::set_block_number(1);
+        let docs: Vec<_> =
+            [b"doc-a", b"doc-b", b"doc-c", b"doc-d"].iter().map(|doc| BlakeTwo256::hash(*doc)).collect();
+        let leaves: Vec<_> = docs.iter().map(|doc| hash_leaf(*doc)).collect();
+        let ab = hash_pair(leaves[0], leaves[1]);
+        let cd = hash_pair(leaves[2], leaves[3]);
+        let root = hash_pair(ab, cd);
+
+        // 未锚定的根不能用于验证
+        assert!(!PoeModule::verify_inclusion(docs[0], &[leaves[1], cd], root));
+
+        assert_ok!(PoeModule::anchor_root(RuntimeOrigin::signed(1), root));
+        System::assert_last_event(Event::RootAnchored { owner: 1, root }.into());
+        assert_noop!(
+            PoeModule::anchor_root(RuntimeOrigin::signed(2), root),
+            Error::<Test>::RootAlreadyAnchored
+        );
+
+        assert!(PoeModule::verify_inclusion(docs[0], &[leaves[1], cd], root));
+        assert!(PoeModule::verify_inclusion(docs[3], &[leaves[2], ab], root));
+        assert!(!PoeModule::verify_inclusion(BlakeTwo256::hash(b"doc-e"), &[leaves[1], cd], root));
+        assert!(!PoeModule::verify_inclusion(docs[0], &[leaves[2], ab], root));
+    });
+}
+
+#[test]
+fn inner_node_is_not_accepted_as_leaf() {
+    build_and_execute(|| {
+        let docs: Vec<_> =
+            [b"doc-a", b"doc-b", b"doc-c", b"doc-d"].iter().map(|doc| BlakeTwo256::hash(*doc)).collect();
+        let leaves: Vec<_> = docs.iter().map(|doc| hash_leaf(*doc)).collect();
+        let ab = hash_pair(leaves[0], leaves[1]);
+        let cd = hash_pair(leaves[2], leaves[3]);
+        let root = hash_pair(ab, cd);
+        assert_ok!(PoeModule::anchor_root(RuntimeOrigin::signed(1), root));
+
+        assert_eq!(PoeModule::merkle_leaf(docs[0]), leaves[0]);
+        assert_eq!(PoeModule::merkle_node(ab, cd), root);
+        // 内部节点 ab 不能冒充叶子，以 cd 为证明得到的根与锚定的根不同
+        assert!(!PoeModule::verify_inclusion(ab, &[cd], root));
+        assert!(!PoeModule::verify_inclusion(leaves[0], &[leaves[1], cd], root));
+    });
+}
+
+#[test]
+fn remove_root_releases_deposit() {
+    build_and_execute(|| {
+        let root = BlakeTwo256::hash(b"root");
+
+        assert_ok!(PoeModule::anchor_root(RuntimeOrigin::signed(1), root));
+        // 押金 = 10 + 32 字节
+        assert_eq!(Balances::balance_on_hold(&HoldReason::ClaimDeposit.into(), &1), 42);
+
+        assert_noop!(PoeModule::remove_root(RuntimeOrigin::signed(2), root), Error::<Test>::NotRootOwner);
+        assert_ok!(PoeModule::remove_root(RuntimeOrigin::signed(1), root));
+        assert_eq!(Balances::balance_on_hold(&HoldReason::ClaimDeposit.into(), &1), 0);
+        assert!(!pallet_poe::Roots::<Test>::contains_key(root));
+    });
+}
+
+#[test]
+fn try_state_counts_root_and_claim_deposits_together() {
+    new_test_ext().execute_with(|| {
+        let root = BlakeTwo256::hash(b"root");
+        let claim = BoundedVec::try_from(vec![0, 1]).unwrap();
+
+        assert_ok!(PoeModule::anchor_root(RuntimeOrigin::signed(1), root));
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone(), None, None));
+        // 根押金 42 与存证押金 12 都以 ClaimDeposit 名义锁定
+        assert_eq!(Balances::balance_on_hold(&HoldReason::ClaimDeposit.into(), &1), 54);
+        assert_ok!(PoeModule::do_try_state());
+
+        assert_ok!(PoeModule::revoke_claim(RuntimeOrigin::signed(1), claim, RevocationReason::Withdrawn));
+        assert_eq!(Balances::balance_on_hold(&HoldReason::ClaimDeposit.into(), &1), 42);
+        assert_ok!(PoeModule::do_try_state());
+
+        // 根的记录丢失后，锁定余额与押金不再一致
+        pallet_poe::Roots::<Test>::remove(root);
+        assert!(PoeModule::do_try_state().is_err());
+    });
+}
+
+#[test]
+fn claim_history_records_chain_of_custody() {
+    let record = |from, to, block, action| ProvenanceRecord { from, to, block, timestamp: Now::get(), action };
+    build_and_execute(|| {
+        let claim = BoundedVec::try_from(vec![0, 1]).unwrap();
+
+        System::set_block_number(1);
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone(), None, None));
+        System::set_block_number(2);
+        assert_ok!(PoeModule::transfer_claim(RuntimeOrigin::signed(1), claim.clone(), 2));
+
+        assert_eq!(
+            PoeModule::claim_history(DEFAULT_NAMESPACE, claim.to_vec()),
+            vec![
+                record(None, Some(1), 1, ProvenanceAction::Created),
+                record(Some(1), Some(2), 2, ProvenanceAction::Transferred),
+            ]
+        );
+
+        System::set_block_number(3);
+        assert_ok!(PoeModule::transfer_claim(RuntimeOrigin::signed(2), claim.clone(), 1));
+        System::set_block_number(4);
+        assert_ok!(PoeModule::revoke_claim(
+            RuntimeOrigin::signed(1),
+            claim.clone(),
+            RevocationReason::Withdrawn
+        ));
+
+        // 历史已满，最早的创建记录被丢弃
+        assert_eq!(
+            PoeModule::claim_history(DEFAULT_NAMESPACE, claim.to_vec()),
+            vec![
+                record(Some(1), Some(2), 2, ProvenanceAction::Transferred),
+                record(Some(2), Some(1), 3, ProvenanceAction::Transferred),
+                record(Some(1), None, 4, ProvenanceAction::Revoked),
+            ]
+        );
+    });
+}
+
+#[test]
+fn revoke_claim_leaves_tombstone() {
+    build_and_execute(|| {
+        System::set_block_number(2);
+        let claim = BoundedVec::try_from(b"example_claim".to_vec()).unwrap();
+
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone(), None, None));
+        assert_noop!(
+            PoeModule::revoke_claim(RuntimeOrigin::signed(1), claim.clone(), RevocationReason::Expired),
+            Error::<Test>::InvalidRevocationReason
+        );
+        assert_ok!(PoeModule::revoke_claim(
+            RuntimeOrigin::signed(1),
+            claim.clone(),
+            RevocationReason::Compromised
+        ));
+
+        assert_eq!(
+            pallet_poe::Tombstones::<Test>::get(DEFAULT_NAMESPACE, &claim),
+            Some(Tombstone {
+                owner: 1,
+                revoker: Some(1),
+                revoked_at: 2,
+                reason: RevocationReason::Compromised
+            })
+        );
+    });
+}
+
+#[test]
+fn reregistration_follows_policy() {
+    build_and_execute(|| {
+        let claim = BoundedVec::try_from(b"example_claim".to_vec()).unwrap();
+
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone(), None, None));
+        assert_ok!(PoeModule::revoke_claim(
+            RuntimeOrigin::signed(1),
+            claim.clone(),
+            RevocationReason::Superseded
+        ));
+
+        // 默认只有原所有者可以重新登记
+        assert_noop!(
+            PoeModule::create_claim(RuntimeOrigin::signed(2), claim.clone(), None, None),
+            Error::<Test>::ReregistrationNotAllowed
+        );
+        Reregistration::set(ReregistrationPolicy::Never);
+        assert_noop!(
+            PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone(), None, None),
+            Error::<Test>::ReregistrationNotAllowed
+        );
+        Reregistration::set(ReregistrationPolicy::PreviousOwner);
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone(), None, None));
+
+        let ClaimInfo { owner, status, .. } = pallet_poe::Proofs::<Test>::get(DEFAULT_NAMESPACE, &claim).unwrap();
+        assert_eq!(owner, 1);
+        assert!(status.is_active());
+        assert_eq!(pallet_poe::Tombstones::<Test>::get(DEFAULT_NAMESPACE, &claim), None);
+        assert_eq!(pallet_poe::ClaimCount::<Test>::get(), 1);
+        assert_noop!(
+            PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone(), None, None),
+            Error::<Test>::ProofAlreadyExist
+        );
+    });
+}
+
+#[test]
+fn expired_claim_leaves_tombstone() {
+    build_and_execute(|| {
+        System::set_block_number(1);
+        let claim = BoundedVec::try_from(b"example_claim".to_vec()).unwrap();
+
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone(), Some(5), None));
+        System::set_block_number(6);
+        PoeModule::on_idle(6, Weight::MAX);
+
+        assert_eq!(
+            pallet_poe::Tombstones::<Test>::get(DEFAULT_NAMESPACE, &claim),
+            Some(Tombstone { owner: 1, revoker: None, revoked_at: 6, reason: RevocationReason::Expired })
+        );
+
+        Reregistration::set(ReregistrationPolicy::Anyone);
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(2), claim.clone(), None, None));
+        assert_eq!(pallet_poe::ClaimCount::<Test>::get(), 1);
+    });
+}
+
+#[test]
+fn approved_operator_can_transfer_once() {
+    build_and_execute(|| {
+        let claim = BoundedVec::try_from(b"example_claim".to_vec()).unwrap();
+
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone(), None, None));
+        assert_noop!(
+            PoeModule::transfer_claim_from(RuntimeOrigin::signed(3), claim.clone(), 1, 2),
+            Error::<Test>::NotProofOwner
+        );
+        assert_noop!(
+            PoeModule::approve(RuntimeOrigin::signed(1), claim.clone(), Some(1)),
+            Error::<Test>::OperatorIsOwner
+        );
+
+        assert_ok!(PoeModule::approve(RuntimeOrigin::signed(1), claim.clone(), Some(3)));
+        System::assert_last_event(
+            Event::OperatorApproved { owner: 1, claim: claim.clone(), operator: Some(3) }.into(),
+        );
+        assert_noop!(
+            PoeModule::transfer_claim_from(RuntimeOrigin::signed(3), claim.clone(), 2, 1),
+            Error::<Test>::OwnerMismatch
+        );
+        assert_ok!(PoeModule::transfer_claim_from(RuntimeOrigin::signed(3), claim.clone(), 1, 2));
+
+        let ClaimInfo { owner, .. } = pallet_poe::Proofs::<Test>::get(DEFAULT_NAMESPACE, &claim).unwrap();
+        assert_eq!(owner, 2);
+        assert_eq!(Balances::balance_on_hold(&HoldReason::ClaimDeposit.into(), &1), 0);
+        assert_eq!(Balances::balance_on_hold(&HoldReason::ClaimDeposit.into(), &3), 0);
+
+        // 转移后授权被清除
+        assert_eq!(pallet_poe::ClaimOperators::<Test>::get(DEFAULT_NAMESPACE, &claim), None);
+        assert_noop!(
+            PoeModule::transfer_claim(RuntimeOrigin::signed(3), claim.clone(), 1),
+            Error::<Test>::NotProofOwner
+        );
+    });
+}
+
+#[test]
+fn operator_for_all_can_revoke() {
+    build_and_execute(|| {
+        System::set_block_number(1);
+        let claim = BoundedVec::try_from(b"example_claim".to_vec()).unwrap();
+
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone(), None, None));
+        assert_ok!(PoeModule::set_operator(RuntimeOrigin::signed(1), 3, true));
+        System::assert_last_event(Event::OperatorSet { owner: 1, operator: 3, approved: true }.into());
+
+        assert_ok!(PoeModule::revoke_claim(
+            RuntimeOrigin::signed(3),
+            claim.clone(),
+            RevocationReason::Compromised
+        ));
+        let tombstone = pallet_poe::Tombstones::<Test>::get(DEFAULT_NAMESPACE, &claim).unwrap();
+        assert_eq!(tombstone.owner, 1);
+        assert_eq!(tombstone.revoker, Some(3));
+        // 押金退还给所有者
+        assert_eq!(Balances::balance_on_hold(&HoldReason::ClaimDeposit.into(), &1), 0);
+        assert_eq!(Balances::free_balance(1), 100);
+
+        assert_ok!(PoeModule::set_operator(RuntimeOrigin::signed(1), 3, false));
+        assert!(!pallet_poe::Operators::<Test>::contains_key(1, 3));
+    });
+}
+
+#[test]
+fn force_calls_require_force_origin() {
+    build_and_execute(|| {
+        let claim = BoundedVec::try_from(b"example_claim".to_vec()).unwrap();
+
+        assert_noop!(
+            PoeModule::force_create_claim(RuntimeOrigin::signed(1), claim.clone(), 1),
+            DispatchError::BadOrigin
+        );
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone(), None, None));
+        assert_noop!(
+            PoeModule::force_transfer_claim(RuntimeOrigin::signed(2), claim.clone(), 2),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            PoeModule::force_revoke_claim(RuntimeOrigin::signed(2), claim, RevocationReason::Compromised),
+            DispatchError::BadOrigin
+        );
+    });
+}
+
+#[test]
+fn force_transfer_and_revoke_skip_ownership_checks() {
+    build_and_execute(|| {
+        System::set_block_number(1);
+        let claim = BoundedVec::try_from(b"example_claim".to_vec()).unwrap();
+        let co_owners = BoundedVec::try_from(vec![1, 2]).unwrap();
+
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone(), None, None));
+        assert_ok!(PoeModule::set_co_owners(RuntimeOrigin::signed(1), claim.clone(), co_owners, 2));
+
+        // 共有存证也可以被强制转移，且只发出强制转移事件
+        let events = System::events().len();
+        assert_ok!(PoeModule::force_transfer_claim(RuntimeOrigin::root(), claim.clone(), 2));
+        assert_eq!(System::events().len(), events + 1);
+        System::assert_last_event(
+            Event::ClaimForceTransferred {
+                old_owner: 1,
+                new_owner: 2,
+                claim: claim.clone(),
+                timestamp: Now::get(),
+            }
+            .into(),
+        );
+        assert!(!pallet_poe::CoOwners::<Test>::contains_key(DEFAULT_NAMESPACE, &claim));
+        assert_eq!(Balances::balance_on_hold(&HoldReason::ClaimDeposit.into(), &1), 0);
+
+        let events = System::events().len();
+        assert_ok!(PoeModule::force_revoke_claim(
+            RuntimeOrigin::root(),
+            claim.clone(),
+            RevocationReason::Compromised
+        ));
+        assert_eq!(System::events().len(), events + 1);
+        System::assert_last_event(
+            Event::ClaimForceRevoked { owner: 2, claim: claim.clone(), reason: RevocationReason::Compromised }
+                .into(),
+        );
+        assert_eq!(pallet_poe::Tombstones::<Test>::get(DEFAULT_NAMESPACE, &claim).unwrap().revoker, None);
+        assert_eq!(Balances::balance_on_hold(&HoldReason::ClaimDeposit.into(), &2), 0);
+    });
+}
+
+#[test]
+fn force_transfer_returns_challenge_bond() {
+    build_and_execute(|| {
+        System::set_block_number(1);
+        let claim = BoundedVec::try_from(b"example_claim".to_vec()).unwrap();
+
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone(), None, None));
+        assert_ok!(PoeModule::challenge_claim(RuntimeOrigin::signed(2), claim.clone()));
+
+        // 强制转移了结争议，质疑保证金退还给质疑人
+        assert_ok!(PoeModule::force_transfer_claim(RuntimeOrigin::root(), claim.clone(), 2));
+        assert!(!pallet_poe::Challenges::<Test>::contains_key(DEFAULT_NAMESPACE, &claim));
+        assert_eq!(pallet_poe::ChallengeQueue::<Test>::iter_keys().count(), 0);
+        assert_eq!(Balances::balance_on_hold(&HoldReason::ChallengeBond.into(), &2), 0);
+        assert_eq!(pallet_poe::Proofs::<Test>::get(DEFAULT_NAMESPACE, &claim).unwrap().owner, 2);
+    });
+}
+
+#[test]
+fn force_create_ignores_reregistration_policy() {
+    build_and_execute(|| {
+        System::set_block_number(1);
+        let claim = BoundedVec::try_from(b"example_claim".to_vec()).unwrap();
+
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone(), None, None));
+        assert_noop!(
+            PoeModule::force_create_claim(RuntimeOrigin::root(), claim.clone(), 2),
+            Error::<Test>::ProofAlreadyExist
+        );
+        assert_ok!(PoeModule::revoke_claim(
+            RuntimeOrigin::signed(1),
+            claim.clone(),
+            RevocationReason::Compromised
+        ));
+        Reregistration::set(ReregistrationPolicy::Never);
+
+        let events = System::events().len();
+        assert_ok!(PoeModule::force_create_claim(RuntimeOrigin::root(), claim.clone(), 2));
+        assert_eq!(System::events().len(), events + 1);
+        System::assert_last_event(Event::ClaimForceCreated { owner: 2, claim: claim.clone() }.into());
+        let ClaimInfo { owner, status, .. } = pallet_poe::Proofs::<Test>::get(DEFAULT_NAMESPACE, &claim).unwrap();
+        assert_eq!(owner, 2);
+        assert!(status.is_active());
+        assert_eq!(pallet_poe::Tombstones::<Test>::get(DEFAULT_NAMESPACE, &claim), None);
+    });
+}
+
+#[test]
+fn challenge_freezes_claim() {
+    build_and_execute(|| {
+        System::set_block_number(1);
+        let claim = BoundedVec::try_from(b"example_claim".to_vec()).unwrap();
+
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone(), None, None));
+        assert_noop!(
+            PoeModule::challenge_claim(RuntimeOrigin::signed(1), claim.clone()),
+            Error::<Test>::CannotChallengeOwnClaim
+        );
+        assert_noop!(
+            PoeModule::challenge_claim(RuntimeOrigin::signed(3), claim.clone()),
+            Error::<Test>::InsufficientBond
+        );
+
+        assert_ok!(PoeModule::challenge_claim(RuntimeOrigin::signed(2), claim.clone()));
+        System::assert_last_event(
+            Event::ClaimChallenged { challenger: 2, claim: claim.clone(), expires_at: 6 }.into(),
+        );
+        assert_eq!(Balances::balance_on_hold(&HoldReason::ChallengeBond.into(), &2), 20);
+        assert_noop!(
+            PoeModule::challenge_claim(RuntimeOrigin::signed(2), claim.clone()),
+            Error::<Test>::AlreadyChallenged
+        );
+
+        assert_noop!(
+            PoeModule::transfer_claim(RuntimeOrigin::signed(1), claim.clone(), 3),
+            Error::<Test>::ClaimDisputed
+        );
+        assert_noop!(
+            PoeModule::revoke_claim(RuntimeOrigin::signed(1), claim.clone(), RevocationReason::Withdrawn),
+            Error::<Test>::ClaimDisputed
+        );
+        assert_noop!(
+            PoeModule::offer_claim(RuntimeOrigin::signed(1), claim.clone(), 3),
+            Error::<Test>::ClaimDisputed
+        );
+    });
+}
+
+#[test]
+fn rejected_challenge_burns_bond() {
+    build_and_execute(|| {
+        let claim = BoundedVec::try_from(b"example_claim".to_vec()).unwrap();
+
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone(), None, None));
+        assert_ok!(PoeModule::challenge_claim(RuntimeOrigin::signed(2), claim.clone()));
+        assert_noop!(
+            PoeModule::resolve_challenge(RuntimeOrigin::signed(1), claim.clone(), Verdict::Rejected),
+            DispatchError::BadOrigin
+        );
+
+        assert_ok!(PoeModule::resolve_challenge(RuntimeOrigin::root(), claim.clone(), Verdict::Rejected));
+        assert_eq!(Balances::balance_on_hold(&HoldReason::ChallengeBond.into(), &2), 0);
+        assert_eq!(Balances::total_balance(&2), 80);
+        assert_eq!(pallet_poe::Challenges::<Test>::get(DEFAULT_NAMESPACE, &claim), None);
+        assert_eq!(pallet_poe::ChallengeQueue::<Test>::iter().count(), 0);
+
+        // 质疑结束后可以正常转移
+        assert_ok!(PoeModule::transfer_claim(RuntimeOrigin::signed(1), claim.clone(), 2));
+    });
+}
+
+#[test]
+fn upheld_challenge_can_reassign_claim() {
+    build_and_execute(|| {
+        let claim = BoundedVec::try_from(b"example_claim".to_vec()).unwrap();
+
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone(), None, None));
+        assert_ok!(PoeModule::challenge_claim(RuntimeOrigin::signed(2), claim.clone()));
+
+        let verdict = Verdict::Upheld { reassign_to: Some(2) };
+        assert_ok!(PoeModule::resolve_challenge(RuntimeOrigin::root(), claim.clone(), verdict.clone()));
+        System::assert_last_event(
+            Event::ChallengeResolved { challenger: 2, claim: claim.clone(), verdict }.into(),
+        );
+
+        let ClaimInfo { owner, .. } = pallet_poe::Proofs::<Test>::get(DEFAULT_NAMESPACE, &claim).unwrap();
+        assert_eq!(owner, 2);
+        assert_eq!(Balances::balance_on_hold(&HoldReason::ChallengeBond.into(), &2), 0);
+        assert_eq!(Balances::balance_on_hold(&HoldReason::ClaimDeposit.into(), &1), 0);
+        assert_eq!(Balances::total_balance(&2), 100);
+    });
+}
+
+#[test]
+fn unresolved_challenge_expires() {
+    build_and_execute(|| {
+        System::set_block_number(1);
+        let claim = BoundedVec::try_from(b"example_claim".to_vec()).unwrap();
+
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone(), None, None));
+        assert_ok!(PoeModule::challenge_claim(RuntimeOrigin::signed(2), claim.clone()));
+
+        PoeModule::on_initialize(5);
+        assert!(pallet_poe::Challenges::<Test>::contains_key(DEFAULT_NAMESPACE, &claim));
+
+        System::set_block_number(6);
+        PoeModule::on_initialize(6);
+        assert_eq!(pallet_poe::Challenges::<Test>::get(DEFAULT_NAMESPACE, &claim), None);
+        assert_eq!(Balances::balance_on_hold(&HoldReason::ChallengeBond.into(), &2), 0);
+        System::assert_last_event(Event::ChallengeExpired { challenger: 2, claim: claim.clone() }.into());
+        assert_noop!(
+            PoeModule::resolve_challenge(RuntimeOrigin::root(), claim, Verdict::Rejected),
+            Error::<Test>::NotChallenged
+        );
+    });
+}
+
+#[test]
+fn migration_v0_to_v1_converts_proofs() {
+    use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+    use pallet_poe::migrations::{v0, v1};
+
+    new_test_ext().execute_with(|| {
+        let active: BoundedVec<u8, ConstU32<100>> = BoundedVec::try_from(b"active".to_vec()).unwrap();
+        let revoked: BoundedVec<u8, ConstU32<100>> = BoundedVec::try_from(b"revoked".to_vec()).unwrap();
+        StorageVersion::new(0).put::<PoeModule>();
+        v0::Proofs::<Test>::insert(&active, (1, 3, true));
+        v0::Proofs::<Test>::insert(&revoked, (2, 5, false));
+
+        v1::MigrateV0ToV1::<Test>::on_runtime_upgrade();
+
+        assert_eq!(PoeModule::on_chain_storage_version(), 1);
+        assert_eq!(
+            v1::Proofs::<Test>::get(&active),
+            Some(v1::ClaimInfo { owner: 1, created_at: 3, updated_at: 3, status: ClaimStatus::Active })
+        );
+        assert_eq!(
+            v1::Proofs::<Test>::get(&revoked),
+            Some(v1::ClaimInfo { owner: 2, created_at: 5, updated_at: 5, status: ClaimStatus::Revoked })
+        );
+
+        // 版本已是 1 时不会再次执行，否则新格式的记录会因无法解码而被丢弃
+        v1::MigrateV0ToV1::<Test>::on_runtime_upgrade();
+        assert_eq!(v1::Proofs::<Test>::iter().count(), 2);
+    });
+}
+
+#[test]
+fn migration_v1_to_v2_adds_times() {
+    use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+    use pallet_poe::migrations::{v1, v2};
+
+    new_test_ext().execute_with(|| {
+        let claim: BoundedVec<u8, ConstU32<100>> = BoundedVec::try_from(b"claim".to_vec()).unwrap();
+        StorageVersion::new(1).put::<PoeModule>();
+        v1::Proofs::<Test>::insert(
+            &claim,
+            v1::ClaimInfo { owner: 1, created_at: 3, updated_at: 4, status: ClaimStatus::Active },
+        );
+        v1::ClaimHistory::<Test>::insert(
+            &claim,
+            BoundedVec::truncate_from(vec![v1::ProvenanceRecord {
+                from: None,
+                to: Some(1),
+                block: 3,
+                action: ProvenanceAction::Created,
+            }]),
+        );
+
+        v2::MigrateV1ToV2::<Test>::on_runtime_upgrade();
+
+        assert_eq!(PoeModule::on_chain_storage_version(), 2);
+        // 迁移前的时间未知，记为 0
+        assert_eq!(
+            v2::Proofs::<Test>::get(&claim),
+            Some(ClaimInfo {
+                owner: 1,
+                created_at: 3,
+                updated_at: 4,
+                created_time: 0,
+                updated_time: 0,
+                status: ClaimStatus::Active,
+            })
+        );
+        assert_eq!(
+            PoeModule::claim_history(DEFAULT_NAMESPACE, claim.to_vec()),
+            vec![ProvenanceRecord {
+                from: None,
+                to: Some(1),
+                block: 3,
+                timestamp: 0,
+                action: ProvenanceAction::Created,
+            }]
+        );
+    });
+}
+
+#[test]
+fn claims_record_wall_clock_time() {
+    build_and_execute(|| {
+        System::set_block_number(1);
+        let claim = BoundedVec::try_from(vec![0, 1]).unwrap();
+        let created = Now::get();
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone(), None, None));
+
+        System::set_block_number(2);
+        Now::set(created + 6_000);
+        assert_ok!(PoeModule::transfer_claim(RuntimeOrigin::signed(1), claim.clone(), 2));
+        System::assert_last_event(
+            Event::ClaimTransferred { old_owner: 1, new_owner: 2, claim: claim.clone(), timestamp: created + 6_000 }
+                .into(),
+        );
+
+        let details = PoeModule::claim_details(DEFAULT_NAMESPACE, claim.to_vec()).unwrap();
+        assert_eq!((details.created_time, details.updated_time), (created, created + 6_000));
+        let history = PoeModule::claim_history(DEFAULT_NAMESPACE, claim.to_vec());
+        assert_eq!(
+            history.iter().map(|record| record.timestamp).collect::<Vec<_>>(),
+            vec![created, created + 6_000]
+        );
+    });
+}
+
+#[test]
+fn create_claim_signed_registers_claim_for_owner() {
+    build_and_execute(|| {
+        System::set_block_number(1);
+        let claim = BoundedVec::try_from(vec![0, 1]).unwrap();
+        let payload = PoeModule::signed_claim_payload(&claim, &1, 0, 10);
+        let signature = TestSignature(1, payload);
+
+        // 账户 2 代为提交并支付手续费，存证归签名者 1 所有
+        assert_ok!(PoeModule::create_claim_signed(
+            RuntimeOrigin::signed(2),
+            claim.clone(),
+            1,
+            signature.clone(),
+            10
+        ));
+        let ClaimInfo { owner, .. } = pallet_poe::Proofs::<Test>::get(DEFAULT_NAMESPACE, &claim).unwrap();
+        assert_eq!(owner, 1);
+        assert_eq!(Balances::balance_on_hold(&HoldReason::ClaimDeposit.into(), &1), 12);
+        assert_eq!(Balances::balance_on_hold(&HoldReason::ClaimDeposit.into(), &2), 0);
+        assert_eq!(pallet_poe::SignedClaimNonces::<Test>::get(1), 1);
+
+        // nonce 已递增，同一签名不能重放
+        assert_ok!(PoeModule::revoke_claim(RuntimeOrigin::signed(1), claim.clone(), RevocationReason::Withdrawn));
+        assert_noop!(
+            PoeModule::create_claim_signed(RuntimeOrigin::signed(2), claim, 1, signature, 10),
+            Error::<Test>::InvalidSignature
+        );
+    });
+}
+
+#[test]
+fn create_claim_signed_rejects_bad_signatures() {
+    build_and_execute(|| {
+        System::set_block_number(5);
+        let claim: BoundedVec<u8, ConstU32<100>> = BoundedVec::try_from(vec![0, 1]).unwrap();
+
+        let expired = TestSignature(1, PoeModule::signed_claim_payload(&claim, &1, 0, 4));
+        assert_noop!(
+            PoeModule::create_claim_signed(RuntimeOrigin::signed(2), claim.clone(), 1, expired, 4),
+            Error::<Test>::SignatureExpired
+        );
+
+        // 签名者不是 owner
+        let wrong_signer = TestSignature(2, PoeModule::signed_claim_payload(&claim, &1, 0, 10));
+        assert_noop!(
+            PoeModule::create_claim_signed(RuntimeOrigin::signed(2), claim.clone(), 1, wrong_signer, 10),
+            Error::<Test>::InvalidSignature
+        );
+
+        // 签名内容与提交的截止区块不一致
+        let other_deadline = TestSignature(1, PoeModule::signed_claim_payload(&claim, &1, 0, 10));
+        assert_noop!(
+            PoeModule::create_claim_signed(RuntimeOrigin::signed(2), claim, 1, other_deadline, 20),
+            Error::<Test>::InvalidSignature
+        );
+    });
+}
+
+#[test]
+fn migration_v2_to_v3_moves_claims_to_default_namespace() {
+    use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+    use pallet_poe::migrations::{v2, v3};
+
+    new_test_ext().execute_with(|| {
+        let claim: BoundedVec<u8, ConstU32<100>> = BoundedVec::try_from(b"claim".to_vec()).unwrap();
+        let info = ClaimInfo {
+            owner: 1,
+            created_at: 3,
+            updated_at: 3,
+            created_time: 0,
+            updated_time: 0,
+            status: ClaimStatus::Active,
+        };
+        StorageVersion::new(2).put::<PoeModule>();
+        v2::Proofs::<Test>::insert(&claim, info.clone());
+        v2::Deposits::<Test>::insert(&claim, 15);
+        v2::Expiries::<Test>::insert(&claim, 20);
+        v2::ExpiryQueue::<Test>::insert(20, &claim, ());
+        v2::ClaimsByOwner::<Test>::insert(1, &claim, ());
+        v2::ClaimOperators::<Test>::insert(&claim, 2);
+
+        v3::MigrateV2ToV3::<Test>::on_runtime_upgrade();
+
+        assert_eq!(PoeModule::on_chain_storage_version(), 3);
+        assert_eq!(pallet_poe::Proofs::<Test>::get(DEFAULT_NAMESPACE, &claim), Some(info));
+        assert_eq!(pallet_poe::Deposits::<Test>::get(DEFAULT_NAMESPACE, &claim), Some(15));
+        assert_eq!(pallet_poe::Proofs::<Test>::iter().count(), 1);
+        assert_eq!(pallet_poe::Expiries::<Test>::get(DEFAULT_NAMESPACE, &claim), Some(20));
+        assert_eq!(
+            pallet_poe::ExpiryQueue::<Test>::get(20, (DEFAULT_NAMESPACE, &claim)),
+            Some(())
+        );
+        assert!(pallet_poe::ClaimsByOwner::<Test>::contains_key(1, (DEFAULT_NAMESPACE, &claim)));
+        assert_eq!(pallet_poe::ClaimOperators::<Test>::get(DEFAULT_NAMESPACE, &claim), Some(2));
+    });
+}
+
+#[test]
+fn migration_v2_to_v3_rebuilds_indexes_and_deposits() {
+    use frame_support::traits::{OnRuntimeUpgrade, StorageVersion};
+    use pallet_poe::migrations::{v2, v3};
+
+    new_test_ext().execute_with(|| {
+        let claim = |bytes: &[u8]| -> BoundedVec<u8, ConstU32<100>> { BoundedVec::try_from(bytes.to_vec()).unwrap() };
+        let info = |owner, status| ClaimInfo {
+            owner,
+            created_at: 3,
+            updated_at: 3,
+            created_time: 0,
+            updated_time: 0,
+            status,
+        };
+        // 旧存证既不在索引中，也没有锁定押金
+        StorageVersion::new(2).put::<PoeModule>();
+        v2::Proofs::<Test>::insert(claim(b"aa"), info(1, ClaimStatus::Active));
+        v2::Proofs::<Test>::insert(claim(b"bb"), info(1, ClaimStatus::Revoked));
+        v2::Proofs::<Test>::insert(claim(b"cc"), info(3, ClaimStatus::Active));
+        // 过时的索引条目会被丢弃
+        v2::ClaimsByOwner::<Test>::insert(2, claim(b"aa"), ());
+
+        v3::MigrateV2ToV3::<Test>::on_runtime_upgrade();
+
+        assert_eq!(pallet_poe::ClaimCount::<Test>::get(), 3);
+        assert_eq!(pallet_poe::OwnerClaimCount::<Test>::get(1), 1);
+        assert_eq!(pallet_poe::OwnerClaimCount::<Test>::get(2), 0);
+        assert_eq!(pallet_poe::OwnerClaimCount::<Test>::get(3), 1);
+        assert!(pallet_poe::ClaimsByOwner::<Test>::contains_key(1, (DEFAULT_NAMESPACE, claim(b"aa"))));
+        assert!(!pallet_poe::ClaimsByOwner::<Test>::contains_key(1, (DEFAULT_NAMESPACE, claim(b"bb"))));
+        assert_eq!(pallet_poe::ClaimsByOwner::<Test>::iter_prefix(2).count(), 0);
+
+        // 押金 = 10 + 2 字节；账户 3 余额不足，只锁定可用的 4
+        assert_eq!(pallet_poe::Deposits::<Test>::get(DEFAULT_NAMESPACE, claim(b"aa")), Some(12));
+        assert_eq!(pallet_poe::Deposits::<Test>::get(DEFAULT_NAMESPACE, claim(b"bb")), None);
+        assert_eq!(pallet_poe::Deposits::<Test>::get(DEFAULT_NAMESPACE, claim(b"cc")), Some(4));
+        assert_eq!(Balances::balance_on_hold(&HoldReason::ClaimDeposit.into(), &1), 12);
+        assert_eq!(Balances::balance_on_hold(&HoldReason::ClaimDeposit.into(), &3), 4);
+        assert_ok!(PoeModule::do_try_state());
+    });
+}
+
+#[test]
+fn namespaces_scope_claims() {
+    build_and_execute(|| {
+        System::set_block_number(1);
+        let claim: BoundedVec<u8, ConstU32<100>> = BoundedVec::try_from(vec![0, 1]).unwrap();
+
+        assert_noop!(
+            PoeModule::create_namespace(RuntimeOrigin::signed(1), 1, NamespacePolicy::Open),
+            DispatchError::BadOrigin
+        );
+        assert_ok!(PoeModule::create_namespace(RuntimeOrigin::root(), 1, NamespacePolicy::Open));
+        System::assert_last_event(
+            Event::NamespaceCreated { namespace: 1, admin: 1, policy: NamespacePolicy::Open }.into(),
+        );
+        // 默认命名空间不能通过命名空间调用访问
+        assert_noop!(
+            PoeModule::create_claim_in(RuntimeOrigin::signed(2), DEFAULT_NAMESPACE, claim.clone()),
+            Error::<Test>::NamespaceNotFound
+        );
+
+        // 同一存证可以分别登记在不同命名空间中
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone(), None, None));
+        assert_ok!(PoeModule::create_claim_in(RuntimeOrigin::signed(2), 1, claim.clone()));
+        assert_noop!(
+            PoeModule::create_claim_in(RuntimeOrigin::signed(1), 1, claim.clone()),
+            Error::<Test>::ProofAlreadyExist
+        );
+        assert_eq!(pallet_poe::Proofs::<Test>::get(1, &claim).unwrap().owner, 2);
+        assert_eq!(pallet_poe::ClaimCount::<Test>::get(), 2);
+        assert_eq!(Balances::balance_on_hold(&HoldReason::ClaimDeposit.into(), &2), 12);
+
+        assert_noop!(
+            PoeModule::transfer_claim_in(RuntimeOrigin::signed(1), 1, claim.clone(), 3),
+            Error::<Test>::NotProofOwner
+        );
+        assert_ok!(PoeModule::transfer_claim_in(RuntimeOrigin::signed(2), 1, claim.clone(), 1));
+        assert_eq!(pallet_poe::Proofs::<Test>::get(1, &claim).unwrap().owner, 1);
+        assert_eq!(Balances::balance_on_hold(&HoldReason::ClaimDeposit.into(), &2), 0);
+
+        // 管理员可以撤销命名空间中的任何存证
+        assert_ok!(PoeModule::transfer_claim_in(RuntimeOrigin::signed(1), 1, claim.clone(), 2));
+        assert_ok!(PoeModule::revoke_claim_in(
+            RuntimeOrigin::signed(1),
+            1,
+            claim.clone(),
+            RevocationReason::Compromised
+        ));
+        System::assert_last_event(
+            Event::NamespacedClaimRevoked {
+                namespace: 1,
+                owner: 2,
+                claim: claim.clone(),
+                reason: RevocationReason::Compromised,
+            }
+            .into(),
+        );
+        assert_eq!(pallet_poe::Proofs::<Test>::get(1, &claim).unwrap().status, ClaimStatus::Revoked);
+        assert_eq!(pallet_poe::Tombstones::<Test>::get(1, &claim).unwrap().revoker, Some(1));
+        assert!(pallet_poe::Proofs::<Test>::get(DEFAULT_NAMESPACE, &claim).unwrap().status.is_active());
+        assert!(!pallet_poe::Tombstones::<Test>::contains_key(DEFAULT_NAMESPACE, &claim));
+        assert_eq!(Balances::balance_on_hold(&HoldReason::ClaimDeposit.into(), &2), 0);
+        assert_eq!(pallet_poe::ClaimCount::<Test>::get(), 2);
+    });
+}
+
+#[test]
+fn namespaced_claims_share_claim_bookkeeping() {
+    build_and_execute(|| {
+        System::set_block_number(1);
+        let claim: BoundedVec<u8, ConstU32<100>> = BoundedVec::try_from(vec![0, 1]).unwrap();
+        assert_ok!(PoeModule::create_namespace(RuntimeOrigin::root(), 1, NamespacePolicy::Open));
+
+        // 同一所有者在两个命名空间中登记相同内容，互不影响
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(2), claim.clone(), None, None));
+        assert_ok!(PoeModule::create_claim_in(RuntimeOrigin::signed(2), 1, claim.clone()));
+        System::assert_last_event(
+            Event::NamespacedClaimCreated { namespace: 1, owner: 2, claim: claim.clone(), timestamp: Now::get() }
+                .into(),
+        );
+        let mut owned = PoeModule::claims_of(2, None, 10);
+        owned.sort();
+        assert_eq!(owned, vec![(DEFAULT_NAMESPACE, claim.to_vec()), (1, claim.to_vec())]);
+        assert_eq!(pallet_poe::OwnerClaimCount::<Test>::get(2), 2);
+        assert_eq!(PoeModule::claim_history(1, claim.to_vec()).len(), 1);
+
+        // 命名空间中的存证同样受每个账户的存证上限约束
+        let other: BoundedVec<u8, ConstU32<100>> = BoundedVec::try_from(vec![2]).unwrap();
+        assert_ok!(PoeModule::create_claim_in(RuntimeOrigin::signed(2), 1, other.clone()));
+        let another: BoundedVec<u8, ConstU32<100>> = BoundedVec::try_from(vec![3]).unwrap();
+        assert_noop!(
+            PoeModule::create_claim_in(RuntimeOrigin::signed(2), 1, another),
+            Error::<Test>::TooManyClaims
+        );
+
+        // 在命名空间中撤销只影响该命名空间中的存证
+        assert_ok!(PoeModule::revoke_claim_in(
+            RuntimeOrigin::signed(2),
+            1,
+            claim.clone(),
+            RevocationReason::Superseded
+        ));
+        assert!(pallet_poe::Tombstones::<Test>::contains_key(1, &claim));
+        assert!(!pallet_poe::ClaimsByOwner::<Test>::contains_key(2, (1, &claim)));
+        assert!(pallet_poe::ClaimsByOwner::<Test>::contains_key(2, (DEFAULT_NAMESPACE, &claim)));
+        assert!(pallet_poe::Proofs::<Test>::get(DEFAULT_NAMESPACE, &claim).unwrap().status.is_active());
+        assert_eq!(PoeModule::claim_history(1, claim.to_vec()).len(), 2);
+        assert_eq!(PoeModule::claim_history(DEFAULT_NAMESPACE, claim.to_vec()).len(), 1);
+    });
+}
+
+#[test]
+fn namespace_policies_restrict_creation() {
+    build_and_execute(|| {
+        System::set_block_number(1);
+        let claim: BoundedVec<u8, ConstU32<100>> = BoundedVec::try_from(vec![0, 1]).unwrap();
+        assert_ok!(PoeModule::create_namespace(RuntimeOrigin::root(), 1, NamespacePolicy::AdminOnly));
+
+        assert_noop!(
+            PoeModule::create_claim_in(RuntimeOrigin::signed(2), 1, claim.clone()),
+            Error::<Test>::NotAllowedInNamespace
+        );
+
+        // 仅管理员或 ForceOrigin 可以修改命名空间
+        assert_noop!(
+            PoeModule::set_namespace_policy(RuntimeOrigin::signed(2), 1, NamespacePolicy::AllowList),
+            Error::<Test>::NotNamespaceAdmin
+        );
+        assert_ok!(PoeModule::set_namespace_policy(RuntimeOrigin::signed(1), 1, NamespacePolicy::AllowList));
+        assert_noop!(
+            PoeModule::create_claim_in(RuntimeOrigin::signed(2), 1, claim.clone()),
+            Error::<Test>::NotAllowedInNamespace
+        );
+        assert_ok!(PoeModule::set_namespace_member(RuntimeOrigin::signed(1), 1, 2, true));
+        assert_ok!(PoeModule::create_claim_in(RuntimeOrigin::signed(2), 1, claim.clone()));
+
+        // 移交管理员后，原管理员失去权限
+        assert_ok!(PoeModule::set_namespace_admin(RuntimeOrigin::root(), 1, 2));
+        assert_noop!(
+            PoeModule::set_namespace_member(RuntimeOrigin::signed(1), 1, 1, true),
+            Error::<Test>::NotNamespaceAdmin
+        );
+        assert_noop!(
+            PoeModule::set_namespace_admin(RuntimeOrigin::signed(2), 2, 1),
+            Error::<Test>::NamespaceNotFound
+        );
+    });
+}
+
+#[test]
+fn genesis_claims_are_registered() {
+    new_test_ext_with_claims(vec![(vec![0, 1], 1), (b"hello".to_vec(), 2)]).execute_with(|| {
+        let claim: BoundedVec<u8, ConstU32<100>> = BoundedVec::try_from(vec![0, 1]).unwrap();
+        assert!(matches!(
+            pallet_poe::Proofs::<Test>::get(DEFAULT_NAMESPACE, &claim),
+            Some(ClaimInfo { owner: 1, status: ClaimStatus::Active, created_time: 0, updated_time: 0, .. })
+        ));
+        assert_eq!(pallet_poe::ClaimHistory::<Test>::get(DEFAULT_NAMESPACE, &claim)[0].timestamp, 0);
+        assert_eq!(pallet_poe::ClaimCount::<Test>::get(), 2);
+        // 创世存证同样锁定押金
+        assert_eq!(Balances::balance_on_hold(&HoldReason::ClaimDeposit.into(), &1), 12);
+        assert_eq!(Balances::balance_on_hold(&HoldReason::ClaimDeposit.into(), &2), 15);
+    });
+}
+
+#[test]
+#[should_panic(expected = "duplicate genesis claim")]
+fn genesis_rejects_duplicate_claims() {
+    new_test_ext_with_claims(vec![(vec![0, 1], 1), (vec![0, 1], 2)]);
+}
+
+#[test]
+#[should_panic(expected = "genesis claim is longer than MaxClaimLength")]
+fn genesis_rejects_overlong_claims() {
+    new_test_ext_with_claims(vec![(vec![0; 101], 1)]);
+}
+
+#[test]
+fn try_state_detects_broken_invariants() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let claim: BoundedVec<u8, ConstU32<100>> = BoundedVec::try_from(vec![0, 1]).unwrap();
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone(), Some(5), None));
+        assert_ok!(PoeModule::do_try_state());
+
+        pallet_poe::ClaimCount::<Test>::put(2);
+        assert!(PoeModule::do_try_state().is_err());
+        pallet_poe::ClaimCount::<Test>::put(1);
+
+        // 记录的押金与实际锁定余额不符
+        pallet_poe::Deposits::<Test>::insert(DEFAULT_NAMESPACE, &claim, 1);
+        assert!(PoeModule::do_try_state().is_err());
+        pallet_poe::Deposits::<Test>::insert(DEFAULT_NAMESPACE, &claim, 12);
+
+        // 过期存证落在清理游标之前，将永远不会被清理
+        pallet_poe::NextExpiryCheck::<Test>::put(7);
+        assert!(PoeModule::do_try_state().is_err());
+    });
+}
+
+#[test]
+fn locked_claim_cannot_be_transferred_or_revoked() {
+    build_and_execute(|| {
+        System::set_block_number(1);
+        let claim: BoundedVec<u8, ConstU32<100>> = BoundedVec::try_from(vec![0, 1]).unwrap();
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone(), None, None));
+
+        assert_noop!(
+            PoeModule::lock_claim(RuntimeOrigin::signed(2), claim.clone(), 10, None),
+            Error::<Test>::NotProofOwner
+        );
+        assert_noop!(
+            PoeModule::lock_claim(RuntimeOrigin::signed(1), claim.clone(), 1, None),
+            Error::<Test>::InvalidLockPeriod
+        );
+        assert_ok!(PoeModule::lock_claim(RuntimeOrigin::signed(1), claim.clone(), 10, None));
+        System::assert_last_event(
+            Event::ClaimLocked { owner: 1, claim: claim.clone(), until: 10, beneficiary: None }.into(),
+        );
+        assert_eq!(PoeModule::claim_details(DEFAULT_NAMESPACE, claim.to_vec()).unwrap().locked_until, Some(10));
+
+        assert_noop!(
+            PoeModule::transfer_claim(RuntimeOrigin::signed(1), claim.clone(), 2),
+            Error::<Test>::ClaimLocked
+        );
+        assert_noop!(
+            PoeModule::revoke_claim(RuntimeOrigin::signed(1), claim.clone(), RevocationReason::Withdrawn),
+            Error::<Test>::ClaimLocked
+        );
+        // 锁定期内不能替换锁，所有者也不能提前解锁
+        assert_noop!(
+            PoeModule::lock_claim(RuntimeOrigin::signed(1), claim.clone(), 20, Some(1)),
+            Error::<Test>::ClaimLocked
+        );
+        assert_noop!(PoeModule::unlock_claim(RuntimeOrigin::signed(1), claim.clone()), Error::<Test>::ClaimLocked);
+
+        // 到期后锁自动失效
+        System::set_block_number(10);
+        assert_eq!(PoeModule::claim_details(DEFAULT_NAMESPACE, claim.to_vec()).unwrap().locked_until, None);
+        assert_ok!(PoeModule::transfer_claim(RuntimeOrigin::signed(1), claim.clone(), 2));
+    });
+}
+
+#[test]
+fn beneficiary_can_release_lock_early() {
+    build_and_execute(|| {
+        System::set_block_number(1);
+        let claim: BoundedVec<u8, ConstU32<100>> = BoundedVec::try_from(vec![0, 1]).unwrap();
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone(), None, None));
+        assert_noop!(PoeModule::unlock_claim(RuntimeOrigin::signed(1), claim.clone()), Error::<Test>::ClaimNotLocked);
+
+        assert_ok!(PoeModule::lock_claim(RuntimeOrigin::signed(1), claim.clone(), 100, Some(3)));
+        assert_noop!(PoeModule::unlock_claim(RuntimeOrigin::signed(2), claim.clone()), Error::<Test>::NotProofOwner);
+        assert_ok!(PoeModule::unlock_claim(RuntimeOrigin::signed(3), claim.clone()));
+        System::assert_last_event(Event::ClaimUnlocked { claim: claim.clone(), by: 3 }.into());
+
+        let ClaimInfo { status, .. } = pallet_poe::Proofs::<Test>::get(DEFAULT_NAMESPACE, &claim).unwrap();
+        assert_eq!(status, ClaimStatus::Active);
+        assert_ok!(PoeModule::revoke_claim(RuntimeOrigin::signed(1), claim, RevocationReason::Withdrawn));
+    });
+}
+
+#[test]
+fn jointly_owned_claim_is_locked_by_approval() {
+    build_and_execute(|| {
+        System::set_block_number(1);
+        let claim: BoundedVec<u8, ConstU32<100>> = BoundedVec::try_from(vec![0, 1]).unwrap();
+        let co_owners = BoundedVec::try_from(vec![1, 2, 3]).unwrap();
+        assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone(), None, None));
+        assert_ok!(PoeModule::set_co_owners(RuntimeOrigin::signed(1), claim.clone(), co_owners, 2));
+        assert_ok!(PoeModule::approve_action(RuntimeOrigin::signed(3), claim.clone(), JointAction::Transfer(3)));
+
+        // 所有者不能单方锁定共有存证
+        assert_noop!(
+            PoeModule::lock_claim(RuntimeOrigin::signed(1), claim.clone(), 10, None),
+            Error::<Test>::RequiresApproval
+        );
+        assert_noop!(
+            PoeModule::approve_action(
+                RuntimeOrigin::signed(1),
+                claim.clone(),
+                JointAction::Lock { until: 1, beneficiary: None }
+            ),
+            Error::<Test>::InvalidLockPeriod
+        );
+
+        let lock = JointAction::Lock { until: 10, beneficiary: Some(3) };
+        assert_ok!(PoeModule::approve_action(RuntimeOrigin::signed(1), claim.clone(), lock.clone()));
+        assert!(PoeModule::claim_details(DEFAULT_NAMESPACE, claim.to_vec()).unwrap().locked_until.is_none());
+
+        assert_ok!(PoeModule::approve_action(RuntimeOrigin::signed(2), claim.clone(), lock.clone()));
+        assert_eq!(PoeModule::claim_details(DEFAULT_NAMESPACE, claim.to_vec()).unwrap().locked_until, Some(10));
+        System::assert_has_event(
+            Event::ClaimLocked { owner: 1, claim: claim.clone(), until: 10, beneficiary: Some(3) }.into(),
+        );
+        System::assert_last_event(Event::ActionExecuted { claim: claim.clone(), action: lock }.into());
+        // 锁定后其他待批准的操作随之失效
+        assert!(pallet_poe::Approvals::<Test>::iter_prefix((DEFAULT_NAMESPACE, claim.clone())).next().is_none());
+
+        // 锁定期间共有人也不能批准其他操作
+        assert_noop!(
+            PoeModule::approve_action(RuntimeOrigin::signed(1), claim, JointAction::Transfer(2)),
+            Error::<Test>::ClaimLocked
+        );
+    });
+}
+
+/// A `create_claim` call for a claim made of `bytes`.
+fn create_claim_call(bytes: Vec<u8>) -> RuntimeCall {
+    RuntimeCall::PoeModule(pallet_poe::Call::create_claim {
+        claim: BoundedVec::try_from(bytes).unwrap(),
+        lifetime: None,
+        metadata: None,
+    })
+}
+
+#[test]
+fn rate_limit_caps_claims_per_window() {
+    build_and_execute(|| {
+        System::set_block_number(1);
+        let info = DispatchInfo::default();
+        for i in 0..3u8 {
+            let call = create_claim_call(vec![i]);
+            assert_ok!(CheckClaimRateLimit::<Test>::new().validate(&1, &call, &info, 0));
+            assert_ok!(CheckClaimRateLimit::<Test>::new().pre_dispatch(&1, &call, &info, 0));
+        }
+        assert_eq!(pallet_poe::ClaimRateWindows::<Test>::get(1), Some((1, 3)));
+
+        // 第四个声明超出配额
+        let call = create_claim_call(vec![3]);
+        assert_eq!(
+            CheckClaimRateLimit::<Test>::new().validate(&1, &call, &info, 0),
+            Err(InvalidTransaction::Custom(RATE_LIMITED).into())
+        );
+        assert_eq!(
+            CheckClaimRateLimit::<Test>::new().pre_dispatch(&1, &call, &info, 0),
+            Err(InvalidTransaction::Custom(RATE_LIMITED).into())
+        );
+        // 其他账户和不创建声明的调用不受影响
+        assert_ok!(CheckClaimRateLimit::<Test>::new().validate(&2, &call, &info, 0));
+        let transfer = RuntimeCall::PoeModule(pallet_poe::Call::transfer_claim {
+            claim: BoundedVec::try_from(vec![0]).unwrap(),
+            new_owner: 2,
+        });
+        assert_ok!(CheckClaimRateLimit::<Test>::new().pre_dispatch(&1, &transfer, &info, 0));
+
+        // 窗口结束后配额重置
+        System::set_block_number(11);
+        assert_ok!(CheckClaimRateLimit::<Test>::new().pre_dispatch(&1, &call, &info, 0));
+        assert_eq!(pallet_poe::ClaimRateWindows::<Test>::get(1), Some((11, 1)));
+    });
+}
+
+#[test]
+fn rate_limit_sets_priority_and_longevity_from_the_window() {
+    build_and_execute(|| {
+        System::set_block_number(1);
+        let info = DispatchInfo::default();
+        let call = create_claim_call(vec![0]);
+
+        // 本交易之后剩余 2 个名额，窗口在第 11 块结束
+        let valid = CheckClaimRateLimit::<Test>::new().validate(&1, &call, &info, 0).unwrap();
+        assert_eq!((valid.priority, valid.longevity), (2, 10));
+
+        assert_ok!(CheckClaimRateLimit::<Test>::new().pre_dispatch(&1, &call, &info, 0));
+        System::set_block_number(8);
+        let valid = CheckClaimRateLimit::<Test>::new().validate(&1, &call, &info, 0).unwrap();
+        assert_eq!((valid.priority, valid.longevity), (1, 3));
+
+        // 不创建声明的调用不受影响
+        let transfer = RuntimeCall::PoeModule(pallet_poe::Call::transfer_claim {
+            claim: BoundedVec::try_from(vec![0]).unwrap(),
+            new_owner: 2,
+        });
+        let valid = CheckClaimRateLimit::<Test>::new().validate(&1, &transfer, &info, 0).unwrap();
+        assert_eq!(valid, ValidTransaction::default());
+    });
+}
+
+#[test]
+fn ended_rate_windows_are_removed_on_idle() {
+    build_and_execute(|| {
+        System::set_block_number(1);
+        let info = DispatchInfo::default();
+        let call = create_claim_call(vec![0]);
+        assert_ok!(CheckClaimRateLimit::<Test>::new().pre_dispatch(&1, &call, &info, 0));
+        System::set_block_number(4);
+        assert_ok!(CheckClaimRateLimit::<Test>::new().pre_dispatch(&2, &call, &info, 0));
+
+        PoeModule::on_idle(10, Weight::MAX);
+        assert!(pallet_poe::ClaimRateWindows::<Test>::contains_key(1));
+
+        PoeModule::on_idle(11, Weight::MAX);
+        assert!(!pallet_poe::ClaimRateWindows::<Test>::contains_key(1));
+        assert!(pallet_poe::ClaimRateWindows::<Test>::contains_key(2));
+        assert_eq!(pallet_poe::RateWindowQueue::<Test>::iter().count(), 1);
+
+        // 新窗口开始时旧窗口移出队列
+        System::set_block_number(14);
+        assert_ok!(CheckClaimRateLimit::<Test>::new().pre_dispatch(&2, &call, &info, 0));
+        assert_eq!(pallet_poe::ClaimRateWindows::<Test>::get(2), Some((14, 1)));
+        assert_eq!(pallet_poe::RateWindowQueue::<Test>::iter_keys().collect::<Vec<_>>(), vec![(24, 2)]);
+    });
+}
+
+#[test]
+fn rate_limit_counts_every_claim_of_a_batch() {
+    build_and_execute(|| {
+        System::set_block_number(1);
+        let info = DispatchInfo::default();
+        let batch = |len: u8| {
+            RuntimeCall::PoeModule(pallet_poe::Call::create_claims {
+                claims: BoundedVec::try_from(
+                    (0..len).map(|i| BoundedVec::try_from(vec![i]).unwrap()).collect::<Vec<_>>(),
+                )
+                .unwrap(),
+                mode: BatchMode::AllOrNothing,
+            })
+        };
+        // 满批次恰好用完一个窗口的名额
+        assert_ok!(CheckClaimRateLimit::<Test>::new().validate(&1, &batch(3), &info, 0));
+        assert_ok!(CheckClaimRateLimit::<Test>::new().pre_dispatch(&1, &batch(2), &info, 0));
+        assert_eq!(
+            CheckClaimRateLimit::<Test>::new().validate(&1, &batch(2), &info, 0),
+            Err(InvalidTransaction::Custom(RATE_LIMITED).into())
+        );
+        assert_ok!(CheckClaimRateLimit::<Test>::new().validate(&1, &create_claim_call(vec![9]), &info, 0));
+    });
+}
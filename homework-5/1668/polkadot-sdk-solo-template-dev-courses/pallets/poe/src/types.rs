@@ -0,0 +1,340 @@
+//! Types used by the PoE pallet and exposed through its runtime API.
+
+use crate::Config;
+use alloc::vec::Vec;
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::{BoundedVec, CloneNoBound, EqNoBound, PartialEqNoBound, RuntimeDebugNoBound};
+use frame_system::pallet_prelude::BlockNumberFor;
+use scale_info::TypeInfo;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+
+/// The prefix of the offchain index keys under which claimed content is kept.
+pub const OFFCHAIN_CONTENT_PREFIX: &[u8] = b"pallet-poe/content/";
+
+/// The offchain index key of the content hashed to `hash` by `create_claim_from_content`.
+pub fn offchain_content_key<Hash: Encode>(hash: &Hash) -> Vec<u8> {
+    (OFFCHAIN_CONTENT_PREFIX, hash).encode()
+}
+
+/// A wall-clock time, in milliseconds since the Unix epoch.
+pub type Moment = u64;
+
+/// The lifecycle state of a claim.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, Debug, TypeInfo, MaxEncodedLen)]
+pub enum ClaimStatus<AccountId, BlockNumber> {
+    /// The claim is valid.
+    Active,
+    /// The claim has been revoked; see its tombstone for the reason.
+    Revoked,
+    /// The claim is valid but cannot be transferred or revoked before block `until`, unless
+    /// `beneficiary` releases it first.
+    Locked { until: BlockNumber, beneficiary: Option<AccountId> },
+}
+
+impl<AccountId, BlockNumber: PartialOrd> ClaimStatus<AccountId, BlockNumber> {
+    /// Whether the claim can still be used. A locked claim is active.
+    pub fn is_active(&self) -> bool {
+        !matches!(self, Self::Revoked)
+    }
+
+    /// Whether the claim is locked against transfer and revocation at block `now`.
+    pub fn is_locked(&self, now: &BlockNumber) -> bool {
+        matches!(self, Self::Locked { until, .. } if now < until)
+    }
+}
+
+/// The record stored for every claim.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct ClaimInfo<AccountId, BlockNumber> {
+    /// The current owner of the claim.
+    pub owner: AccountId,
+    /// The block in which the claim was registered.
+    pub created_at: BlockNumber,
+    /// The block in which the claim last changed owner, status or metadata.
+    pub updated_at: BlockNumber,
+    /// The time at which the claim was registered.
+    pub created_time: Moment,
+    /// The time of the last change of owner, status or metadata.
+    pub updated_time: Moment,
+    /// Whether the claim is active, locked or revoked.
+    pub status: ClaimStatus<AccountId, BlockNumber>,
+}
+
+/// A read-only view of a claim, as returned by the runtime API.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, Debug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct ClaimDetails<AccountId, BlockNumber> {
+    /// The current owner of the claim.
+    pub owner: AccountId,
+    /// The block in which the claim was created.
+    pub created_at: BlockNumber,
+    /// The block in which the claim last changed owner, status or metadata.
+    pub updated_at: BlockNumber,
+    /// The time at which the claim was created.
+    pub created_time: Moment,
+    /// The time of the last change of owner, status or metadata.
+    pub updated_time: Moment,
+    /// Whether the claim is neither revoked nor expired.
+    pub is_active: bool,
+    /// The block from which the claim is no longer valid, if it is time-limited.
+    pub expires_at: Option<BlockNumber>,
+    /// The block until which the claim cannot be transferred or revoked, if it is locked.
+    pub locked_until: Option<BlockNumber>,
+}
+
+/// A transfer offer waiting for its recipient to accept it.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct PendingTransfer<AccountId, BlockNumber> {
+    /// The account that may accept the offer.
+    pub to: AccountId,
+    /// The block from which the offer can no longer be accepted.
+    pub expires_at: BlockNumber,
+}
+
+/// The co-owners of a jointly owned claim and how many of them must approve an action.
+#[derive(
+    CloneNoBound, EqNoBound, PartialEqNoBound, RuntimeDebugNoBound, Encode, Decode, TypeInfo, MaxEncodedLen,
+)]
+#[codec(mel_bound(T: Config))]
+#[scale_info(skip_type_params(T))]
+pub struct JointOwnership<T: Config> {
+    /// The accounts that share the claim, including its owner.
+    pub co_owners: BoundedVec<T::AccountId, T::MaxCoOwners>,
+    /// The number of co-owner approvals needed to revoke or transfer the claim.
+    pub threshold: u32,
+}
+
+/// An action on a jointly owned claim that needs co-owner approval.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, Debug, TypeInfo, MaxEncodedLen)]
+pub enum JointAction<AccountId, BlockNumber> {
+    /// Revoke the claim for the given reason.
+    Revoke(RevocationReason),
+    /// Transfer the claim to the given account, which becomes its sole owner.
+    Transfer(AccountId),
+    /// Lock the claim until block `until`, as `lock_claim` does for a solely owned claim.
+    Lock { until: BlockNumber, beneficiary: Option<AccountId> },
+}
+
+/// An action on a jointly owned claim and the co-owners who have approved it so far.
+#[derive(
+    CloneNoBound, EqNoBound, PartialEqNoBound, RuntimeDebugNoBound, Encode, Decode, TypeInfo, MaxEncodedLen,
+)]
+#[codec(mel_bound(T: Config))]
+#[scale_info(skip_type_params(T))]
+pub struct PendingApproval<T: Config> {
+    /// The action being approved.
+    pub action: JointAction<T::AccountId, BlockNumberFor<T>>,
+    /// The co-owners who have approved the action.
+    pub approvals: BoundedVec<T::AccountId, T::MaxCoOwners>,
+}
+
+/// Descriptive metadata attached to a claim.
+#[derive(
+    CloneNoBound, EqNoBound, PartialEqNoBound, RuntimeDebugNoBound, Encode, Decode, TypeInfo, MaxEncodedLen,
+)]
+#[codec(mel_bound(T: Config))]
+#[scale_info(skip_type_params(T))]
+pub struct ClaimMetadata<T: Config> {
+    /// The MIME type of the notarised content, e.g. `application/pdf`.
+    pub content_type: BoundedVec<u8, T::MaxContentTypeLength>,
+    /// Where the notarised content can be found off-chain.
+    pub uri: BoundedVec<u8, T::MaxUriLength>,
+    /// A short human-readable description of the content.
+    pub description: BoundedVec<u8, T::MaxDescriptionLength>,
+}
+
+impl<T: Config> ClaimMetadata<T> {
+    /// The number of bytes the metadata adds to the claim, used to size its deposit.
+    pub fn byte_len(&self) -> u32 {
+        (self.content_type.len() + self.uri.len() + self.description.len()) as u32
+    }
+}
+
+/// How a batch call treats items that fail.
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, Debug, TypeInfo, MaxEncodedLen)]
+pub enum BatchMode {
+    /// Abort the whole call, and revert every item, on the first failure.
+    AllOrNothing,
+    /// Skip failed items and report them in the `BatchCompleted` event.
+    BestEffort,
+}
+
+/// The byte prepended to a document hash before hashing it into a Merkle leaf.
+pub const MERKLE_LEAF_PREFIX: u8 = 0x00;
+
+/// The byte prepended to a pair of child nodes before hashing them into their parent.
+pub const MERKLE_NODE_PREFIX: u8 = 0x01;
+
+/// A Merkle root anchored on chain, covering a set of documents.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct AnchoredRoot<AccountId, BlockNumber, Balance> {
+    /// The account that anchored the root.
+    pub owner: AccountId,
+    /// The block in which the root was anchored.
+    pub anchored_at: BlockNumber,
+    /// The deposit held from the owner for this root.
+    pub deposit: Balance,
+}
+
+/// A claim created with `create_claim_from_content`, stored under the content hash.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct HashedClaim<AccountId, BlockNumber, Balance> {
+    /// The current owner of the claim.
+    pub owner: AccountId,
+    /// The block in which the claim was registered.
+    pub created_at: BlockNumber,
+    /// The time at which the claim was registered.
+    pub created_time: Moment,
+    /// The deposit held from the owner for this claim.
+    pub deposit: Balance,
+}
+
+/// What happened to a claim in a [`ProvenanceRecord`].
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, Debug, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub enum ProvenanceAction {
+    /// The claim was created.
+    Created,
+    /// The claim changed owner.
+    Transferred,
+    /// The claim was revoked.
+    Revoked,
+    /// The lifetime of the claim ran out and it was purged.
+    Expired,
+}
+
+/// One entry in the chain of custody of a claim.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, Debug, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct ProvenanceRecord<AccountId, BlockNumber> {
+    /// The owner before the action, `None` on creation.
+    pub from: Option<AccountId>,
+    /// The owner after the action, `None` on revocation.
+    pub to: Option<AccountId>,
+    /// The block in which the action happened.
+    pub block: BlockNumber,
+    /// The time at which the action happened.
+    pub timestamp: Moment,
+    /// The action taken.
+    pub action: ProvenanceAction,
+}
+
+/// Why a claim stopped being valid.
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, Debug, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub enum RevocationReason {
+    /// A newer document replaces the claimed one.
+    Superseded,
+    /// The claimed document or the owner's key can no longer be trusted.
+    Compromised,
+    /// The owner no longer stands behind the claim.
+    Withdrawn,
+    /// The lifetime of the claim ran out. Only set by the pallet itself.
+    Expired,
+}
+
+/// What remains of a revoked or expired claim.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct Tombstone<AccountId, BlockNumber> {
+    /// The owner of the claim when it was revoked.
+    pub owner: AccountId,
+    /// The account that revoked the claim, `None` if it expired or was revoked by the
+    /// pallet's `ForceOrigin`.
+    pub revoker: Option<AccountId>,
+    /// The block in which the claim was revoked.
+    pub revoked_at: BlockNumber,
+    /// Why the claim was revoked.
+    pub reason: RevocationReason,
+}
+
+/// Who may register a claim again once it has been revoked or has expired.
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, Debug, TypeInfo, MaxEncodedLen)]
+pub enum ReregistrationPolicy {
+    /// Nobody; a revoked claim stays revoked.
+    Never,
+    /// Only the owner recorded in the tombstone.
+    PreviousOwner,
+    /// Any account.
+    Anyone,
+}
+
+impl ReregistrationPolicy {
+    /// Whether `who` may register again the claim described by `tombstone`.
+    pub fn allows<AccountId: PartialEq, BlockNumber>(
+        &self,
+        who: &AccountId,
+        tombstone: &Tombstone<AccountId, BlockNumber>,
+    ) -> bool {
+        match self {
+            Self::Never => false,
+            Self::PreviousOwner => *who == tombstone.owner,
+            Self::Anyone => true,
+        }
+    }
+}
+
+/// A pending dispute over the ownership of a claim.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct Challenge<AccountId, Balance, BlockNumber> {
+    /// The account that raised the challenge.
+    pub challenger: AccountId,
+    /// The bond held from the challenger.
+    pub bond: Balance,
+    /// The block in which the challenge expires if nobody resolves it.
+    pub expires_at: BlockNumber,
+}
+
+/// The decision of the arbiter on a challenge.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, Debug, TypeInfo, MaxEncodedLen)]
+pub enum Verdict<AccountId> {
+    /// The challenge is unfounded; the challenger's bond is burned.
+    Rejected,
+    /// The challenge is founded; the challenger's bond is returned and the claim is optionally
+    /// given to another account.
+    Upheld { reassign_to: Option<AccountId> },
+}
+
+/// The identifier of a claim registry.
+pub type NamespaceId = u32;
+
+/// The namespace of the claims made through the calls that do not take a namespace. It has no
+/// admin and is open to everyone.
+pub const DEFAULT_NAMESPACE: NamespaceId = 0;
+
+/// Who may create claims in a namespace.
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, Debug, TypeInfo, MaxEncodedLen)]
+pub enum NamespacePolicy {
+    /// Any account.
+    Open,
+    /// The admin and the accounts the admin has allowed.
+    AllowList,
+    /// Only the admin.
+    AdminOnly,
+}
+
+/// A claim registry with its own admin.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct Namespace<AccountId> {
+    /// The account that manages the namespace and may revoke any of its claims.
+    pub admin: AccountId,
+    /// Who may create claims in the namespace.
+    pub policy: NamespacePolicy,
+}
+
+impl<AccountId: PartialEq> Namespace<AccountId> {
+    /// Whether `who` may create claims in the namespace. `is_member` is only consulted for
+    /// allow-list namespaces.
+    pub fn allows(&self, who: &AccountId, is_member: impl FnOnce() -> bool) -> bool {
+        *who == self.admin
+            || match self.policy {
+                NamespacePolicy::Open => true,
+                NamespacePolicy::AllowList => is_member(),
+                NamespacePolicy::AdminOnly => false,
+            }
+    }
+}
@@ -99,10 +99,10 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
     //   `spec_version`, and `authoring_version` are the same between Wasm and native.
     // This value is set to 100 to notify Polkadot-JS App (https://polkadot.js.org/apps) to use
     //   the compatible custom types.
-    spec_version: 100,
+    spec_version: 104,
     impl_version: 1,
     apis: RUNTIME_API_VERSIONS,
-    transaction_version: 1,
+    transaction_version: 2,
     state_version: 1,
 };
 
@@ -223,7 +223,7 @@ impl pallet_balances::Config for Runtime {
     type WeightInfo = pallet_balances::weights::SubstrateWeight<Runtime>;
     type FreezeIdentifier = ();
     type MaxFreezes = ();
-    type RuntimeHoldReason = ();
+    type RuntimeHoldReason = RuntimeHoldReason;
     type RuntimeFreezeReason = ();
 }
 
@@ -252,9 +252,48 @@ impl pallet_template::Config for Runtime {
     type WeightInfo = pallet_template::weights::SubstrateWeight<Runtime>;
 }
 
+parameter_types! {
+    /// The base deposit held for every PoE claim.
+    pub const ClaimDeposit: Balance = 100 * EXISTENTIAL_DEPOSIT;
+    /// The additional deposit held per byte of PoE claim.
+    pub const DepositPerByte: Balance = EXISTENTIAL_DEPOSIT;
+    /// The bond held from an account challenging a PoE claim.
+    pub const ChallengeBond: Balance = 1000 * EXISTENTIAL_DEPOSIT;
+    /// Only the previous owner may register a revoked PoE claim again.
+    pub const Reregistration: pallet_poe::ReregistrationPolicy =
+        pallet_poe::ReregistrationPolicy::PreviousOwner;
+}
+
 impl pallet_poe::Config for Runtime {
         type MaxClaimLength = ConstU32<100>;
         type RuntimeEvent = RuntimeEvent;
+        type RuntimeHoldReason = RuntimeHoldReason;
+        type WeightInfo = pallet_poe::weights::SubstrateWeight<Runtime>;
+        type UnixTime = Timestamp;
+        type OffchainSignature = Signature;
+        type OffchainPublic = <Signature as Verify>::Signer;
+        type MaxClaimsPerAccountPerWindow = ConstU32<100>;
+        type WindowLength = ConstU32<HOURS>;
+        type Currency = Balances;
+        type ForceOrigin = frame_system::EnsureRoot<AccountId>;
+        type ArbiterOrigin = frame_system::EnsureRoot<AccountId>;
+        type ChallengeBond = ChallengeBond;
+        type ChallengePeriod = ConstU32<{ 14 * DAYS }>;
+        type ClaimDeposit = ClaimDeposit;
+        type DepositPerByte = DepositPerByte;
+        type MaxClaimsPerOwner = ConstU32<1000>;
+        type OfferTimeout = ConstU32<{ 7 * DAYS }>;
+        type AllowDirectTransfer = ConstBool<true>;
+        type Reregistration = Reregistration;
+        type MaxContentLength = ConstU32<{ 64 * 1024 }>;
+        type MaxBatchSize = ConstU32<16>;
+        type MaxHistoryLength = ConstU32<32>;
+        type MaxCoOwners = ConstU32<16>;
+        type MaxContentTypeLength = ConstU32<64>;
+        type MaxUriLength = ConstU32<256>;
+        type MaxDescriptionLength = ConstU32<256>;
+        #[cfg(feature = "runtime-benchmarks")]
+        type BenchmarkHelper = ();
 }
 
 
@@ -319,13 +358,18 @@ pub type SignedExtra = (
     frame_system::CheckNonce<Runtime>,
     frame_system::CheckWeight<Runtime>,
     pallet_transaction_payment::ChargeTransactionPayment<Runtime>,
+    pallet_poe::CheckClaimRateLimit<Runtime>,
 );
 
 /// All migrations of the runtime, aside from the ones declared in the pallets.
 ///
 /// This can be a tuple of types, each implementing `OnRuntimeUpgrade`.
 #[allow(unused_parens)]
-type Migrations = ();
+type Migrations = (
+    pallet_poe::migrations::v1::MigrateV0ToV1<Runtime>,
+    pallet_poe::migrations::v2::MigrateV1ToV2<Runtime>,
+    pallet_poe::migrations::v3::MigrateV2ToV3<Runtime>,
+);
 
 /// Unchecked extrinsic type as expected by this runtime.
 pub type UncheckedExtrinsic =
@@ -523,6 +567,34 @@ impl_runtime_apis! {
         }
     }
 
+    impl pallet_poe_runtime_api::PoeApi<Block, AccountId, BlockNumber, Hash> for Runtime {
+        fn claim(
+            namespace: pallet_poe::NamespaceId,
+            claim: Vec<u8>,
+        ) -> Option<pallet_poe::ClaimDetails<AccountId, BlockNumber>> {
+            PoeModule::claim_details(namespace, claim)
+        }
+        fn claims_of(
+            account: AccountId,
+            cursor: Option<(pallet_poe::NamespaceId, Vec<u8>)>,
+            limit: u32,
+        ) -> Vec<(pallet_poe::NamespaceId, Vec<u8>)> {
+            PoeModule::claims_of(account, cursor, limit)
+        }
+        fn claim_history(
+            namespace: pallet_poe::NamespaceId,
+            claim: Vec<u8>,
+        ) -> Vec<pallet_poe::ProvenanceRecord<AccountId, BlockNumber>> {
+            PoeModule::claim_history(namespace, claim)
+        }
+        fn claim_count() -> u64 {
+            pallet_poe::ClaimCount::<Runtime>::get()
+        }
+        fn verify_inclusion(leaf: Hash, proof: Vec<Hash>, root: Hash) -> bool {
+            PoeModule::verify_inclusion(leaf, &proof, root)
+        }
+    }
+
     #[cfg(feature = "runtime-benchmarks")]
     impl frame_benchmarking::Benchmark<Block> for Runtime {
         fn benchmark_metadata(extra: bool) -> (